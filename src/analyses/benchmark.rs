@@ -0,0 +1,253 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use super::evaluation::Evaluation;
+use crate::{
+    graph::{
+        generators::{Generators, planted::LfrParameters},
+        undirected::{
+            Community, UndirectedGraph,
+            communities::{
+                context::{Budget, RunContext},
+                detector::CommunityDetector,
+            },
+        },
+    },
+    utils::memory::TrackingAllocator,
+};
+
+pub struct BenchmarkCase<T> {
+    pub name: String,
+    pub graph: UndirectedGraph<T>,
+    pub ground_truth: Option<Vec<Community<T>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkRow {
+    pub case: String,
+    pub algorithm: String,
+    pub vertices: usize,
+    pub edges: usize,
+    pub runtime: Duration,
+    /*
+     *  Pico de bytes alocados durante a execução, além do que já estava alocado antes dela.
+     *  None quando o binário foi compilado com `--no-default-features`, sem `track-memory`.
+     */
+    pub peak_memory: Option<usize>,
+    pub modularity: f64,
+    pub communities: usize,
+    pub nmi: Option<f64>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkReport {
+    pub rows: Vec<BenchmarkRow>,
+}
+
+pub struct Benchmark;
+
+impl Benchmark {
+    // Limite de cada linha quando o contexto não define um; a linha sai marcada como truncada
+    pub const ROW_TIME_LIMIT: Duration = Duration::from_secs(60);
+
+    // Um grafo LFR por valor de mu, todos com os demais parâmetros e a semente iguais
+    pub fn lfr_sweep(
+        mixings: &[f64],
+        parameters: &LfrParameters,
+        seed: u64,
+    ) -> Vec<BenchmarkCase<usize>> {
+        mixings
+            .iter()
+            .map(|mixing| {
+                let planted = Generators::lfr(
+                    &LfrParameters {
+                        mixing: *mixing,
+                        ..*parameters
+                    },
+                    seed,
+                );
+
+                BenchmarkCase {
+                    name: format!("lfr_mu_{}", mixing),
+                    graph: planted.graph,
+                    ground_truth: Some(planted.ground_truth),
+                }
+            })
+            .collect()
+    }
+
+    pub fn run<T>(
+        cases: &[BenchmarkCase<T>],
+        detectors: &[Box<dyn CommunityDetector<T>>],
+        context: &RunContext,
+    ) -> BenchmarkReport
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let mut report = BenchmarkReport::default();
+        let context = RunContext {
+            observer: context.observer,
+            budget: Budget {
+                time_limit: context.budget.time_limit.or(Some(Self::ROW_TIME_LIMIT)),
+                ..context.budget
+            },
+            cancellation: context.cancellation.clone(),
+            seed: context.seed,
+        };
+
+        for case in cases {
+            for detector in detectors {
+                let baseline = TrackingAllocator::reset_peak();
+                let start = Instant::now();
+                let partition = detector.detect_with(&case.graph, &context);
+                let runtime = start.elapsed();
+                let peak_memory = TrackingAllocator::is_installed()
+                    .then(|| TrackingAllocator::peak().saturating_sub(baseline));
+
+                report.rows.push(BenchmarkRow {
+                    case: case.name.clone(),
+                    algorithm: detector.name().to_string(),
+                    vertices: case.graph.vertices.len(),
+                    edges: case.graph.get_total_of_edges() / 2,
+                    runtime,
+                    peak_memory,
+                    modularity: case.graph.get_modularity(partition.communities.clone()),
+                    communities: partition.len(),
+                    nmi: case
+                        .ground_truth
+                        .as_ref()
+                        .map(|truth| Evaluation::compare(&partition.communities, truth).nmi),
+                    truncated: partition.truncated,
+                });
+            }
+        }
+
+        report
+    }
+}
+
+impl BenchmarkReport {
+    const HEADER: [&'static str; 10] = [
+        "case",
+        "algorithm",
+        "vertices",
+        "edges",
+        "runtime_s",
+        "peak_memory_bytes",
+        "modularity",
+        "communities",
+        "nmi",
+        "truncated",
+    ];
+
+    fn fields(row: &BenchmarkRow) -> [String; 10] {
+        [
+            row.case.clone(),
+            row.algorithm.clone(),
+            row.vertices.to_string(),
+            row.edges.to_string(),
+            format!("{:.6}", row.runtime.as_secs_f64()),
+            row.peak_memory
+                .map_or(String::new(), |peak| peak.to_string()),
+            format!("{:.6}", row.modularity),
+            row.communities.to_string(),
+            row.nmi.map_or(String::new(), |nmi| format!("{:.6}", nmi)),
+            row.truncated.to_string(),
+        ]
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = Self::HEADER.join(",") + "\n";
+        for row in &self.rows {
+            csv += &(Self::fields(row).join(",") + "\n");
+        }
+        csv
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("| {} |\n", Self::HEADER.join(" | "));
+        markdown += &format!("|{}\n", " --- |".repeat(Self::HEADER.len()));
+        for row in &self.rows {
+            markdown += &format!("| {} |\n", Self::fields(row).join(" | "));
+        }
+        markdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analyses::Analyses,
+        graph::undirected::communities::detector::{GirvanNewman, NewmanModularity},
+    };
+
+    fn cases() -> Vec<BenchmarkCase<usize>> {
+        let planted = Generators::connected_caveman(4, 5);
+        vec![BenchmarkCase {
+            name: String::from("caveman"),
+            graph: planted.graph,
+            ground_truth: Some(planted.ground_truth),
+        }]
+    }
+
+    #[test]
+    fn default_detectors_include_sampled_betweenness() {
+        let names: Vec<String> = Analyses
+            .get_detectors::<usize>()
+            .iter()
+            .map(|detector| detector.name().to_string())
+            .collect();
+
+        assert!(names.contains(&String::from("betweenness")));
+    }
+
+    #[test]
+    fn truncated_runs_are_reported() {
+        let detectors: Vec<Box<dyn CommunityDetector<usize>>> = vec![
+            Box::new(GirvanNewman::default()),
+            Box::new(NewmanModularity::default()),
+        ];
+        let context = RunContext::default().with_budget(Budget {
+            max_iterations: Some(1),
+            time_limit: None,
+        });
+        let report = Benchmark::run(&cases(), &detectors, &context);
+
+        assert_eq!(report.rows.len(), 2);
+        assert!(report.rows.iter().all(|row| row.truncated));
+        assert_eq!(
+            report.rows[0].peak_memory.is_some(),
+            cfg!(feature = "track-memory")
+        );
+
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("caveman,betweenness,20,40,"));
+        assert!(lines[1..].iter().all(|line| line.ends_with(",true")));
+        assert_eq!(report.to_markdown().lines().count(), 4);
+    }
+
+    #[test]
+    fn complete_runs_recover_disjoint_cliques() {
+        let detectors: Vec<Box<dyn CommunityDetector<usize>>> =
+            vec![Box::new(NewmanModularity::default())];
+        let planted = Generators::planted_partition(3, 6, 1., 0., 1);
+        let cases = vec![BenchmarkCase {
+            name: String::from("cliques"),
+            graph: planted.graph,
+            ground_truth: Some(planted.ground_truth),
+        }];
+        let report = Benchmark::run(&cases, &detectors, &RunContext::default());
+        let row = &report.rows[0];
+
+        assert!(!row.truncated);
+        assert_eq!(row.communities, 3);
+        assert!(row.nmi.is_some_and(|nmi| (nmi - 1.).abs() < 1e-12));
+    }
+}
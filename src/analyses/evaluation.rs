@@ -0,0 +1,169 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::graph::undirected::Community;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Evaluation {
+    pub nmi: f64,
+    pub ari: f64,
+    pub common_vertices: usize,
+}
+
+impl Evaluation {
+    /*
+     *  Compara duas partições considerando apenas os vértices presentes em ambas. O NMI usa a
+     *  normalização pela média aritmética das entropias.
+     */
+    pub fn compare<T>(found: &[Community<T>], truth: &[Community<T>]) -> Self
+    where
+        T: Eq + Hash + Clone,
+    {
+        let truth_membership: HashMap<&T, usize> = truth
+            .iter()
+            .enumerate()
+            .flat_map(|(label, community)| community.iter().map(move |vertex| (vertex, label)))
+            .collect();
+
+        let mut contingency: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut found_sizes: HashMap<usize, usize> = HashMap::new();
+        let mut truth_sizes: HashMap<usize, usize> = HashMap::new();
+        let mut common_vertices = 0;
+
+        for (found_label, community) in found.iter().enumerate() {
+            for vertex in community {
+                if let Some(truth_label) = truth_membership.get(vertex) {
+                    *contingency.entry((found_label, *truth_label)).or_insert(0) += 1;
+                    *found_sizes.entry(found_label).or_insert(0) += 1;
+                    *truth_sizes.entry(*truth_label).or_insert(0) += 1;
+                    common_vertices += 1;
+                }
+            }
+        }
+
+        if common_vertices == 0 {
+            return Self::default();
+        }
+
+        Self {
+            nmi: Self::nmi(&contingency, &found_sizes, &truth_sizes, common_vertices),
+            ari: Self::ari(&contingency, &found_sizes, &truth_sizes, common_vertices),
+            common_vertices,
+        }
+    }
+
+    fn entropy(sizes: &HashMap<usize, usize>, total: f64) -> f64 {
+        sizes
+            .values()
+            .map(|size| {
+                let p = *size as f64 / total;
+                -p * p.ln()
+            })
+            .sum()
+    }
+
+    fn nmi(
+        contingency: &HashMap<(usize, usize), usize>,
+        found_sizes: &HashMap<usize, usize>,
+        truth_sizes: &HashMap<usize, usize>,
+        total: usize,
+    ) -> f64 {
+        let total = total as f64;
+        let mutual_information: f64 = contingency
+            .iter()
+            .map(|((found, truth), count)| {
+                let joint = *count as f64 / total;
+                let found = found_sizes[found] as f64 / total;
+                let truth = truth_sizes[truth] as f64 / total;
+                joint * (joint / (found * truth)).ln()
+            })
+            .sum();
+
+        let entropies = Self::entropy(found_sizes, total) + Self::entropy(truth_sizes, total);
+        if entropies == 0. {
+            return 1.;
+        }
+
+        (2. * mutual_information / entropies).clamp(0., 1.)
+    }
+
+    fn ari(
+        contingency: &HashMap<(usize, usize), usize>,
+        found_sizes: &HashMap<usize, usize>,
+        truth_sizes: &HashMap<usize, usize>,
+        total: usize,
+    ) -> f64 {
+        let pairs = |n: usize| (n * n.saturating_sub(1)) as f64 / 2.;
+
+        let index: f64 = contingency.values().map(|count| pairs(*count)).sum();
+        let found_pairs: f64 = found_sizes.values().map(|size| pairs(*size)).sum();
+        let truth_pairs: f64 = truth_sizes.values().map(|size| pairs(*size)).sum();
+        let expected = found_pairs * truth_pairs / pairs(total).max(1.);
+        let maximum = (found_pairs + truth_pairs) / 2.;
+
+        if maximum == expected {
+            return 1.;
+        }
+
+        (index - expected) / (maximum - expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partition(communities: &[&[usize]]) -> Vec<Community<usize>> {
+        communities
+            .iter()
+            .map(|community| community.iter().copied().collect())
+            .collect()
+    }
+
+    fn assert_close(x: f64, y: f64) {
+        assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+    }
+
+    #[test]
+    fn identical_partitions_score_one() {
+        let found = partition(&[&[0, 1, 2], &[3, 4], &[5]]);
+        let truth = partition(&[&[5], &[3, 4], &[0, 1, 2]]);
+        let evaluation = Evaluation::compare(&found, &truth);
+
+        assert_close(evaluation.nmi, 1.);
+        assert_close(evaluation.ari, 1.);
+        assert_eq!(evaluation.common_vertices, 6);
+    }
+
+    #[test]
+    fn known_partitions() {
+        let found = partition(&[&[0, 1, 2], &[3, 4, 5]]);
+        let truth = partition(&[&[0, 1], &[2, 3], &[4, 5]]);
+        let evaluation = Evaluation::compare(&found, &truth);
+
+        // I = 2/3 ln 2, H(found) = ln 2, H(truth) = ln 3
+        assert_close(evaluation.nmi, 4. / 3. * 2f64.ln() / 6f64.ln());
+        // índice 2, esperado 6 × 3 / 15, máximo (6 + 3) / 2
+        assert_close(evaluation.ari, 8. / 33.);
+    }
+
+    #[test]
+    fn single_community_against_singletons_scores_zero() {
+        let found = partition(&[&[0, 1, 2, 3]]);
+        let truth = partition(&[&[0], &[1], &[2], &[3]]);
+        let evaluation = Evaluation::compare(&found, &truth);
+
+        assert_close(evaluation.nmi, 0.);
+        assert_close(evaluation.ari, 0.);
+    }
+
+    #[test]
+    fn only_common_vertices_are_compared() {
+        let found = partition(&[&[0, 1, 7], &[2, 3, 8]]);
+        let truth = partition(&[&[0, 1], &[2, 3], &[9]]);
+        let evaluation = Evaluation::compare(&found, &truth);
+
+        assert_eq!(evaluation.common_vertices, 4);
+        assert_close(evaluation.nmi, 1.);
+        assert_close(evaluation.ari, 1.);
+    }
+}
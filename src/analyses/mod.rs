@@ -1,15 +1,71 @@
+pub mod benchmark;
+pub mod evaluation;
+pub mod resolution;
+pub mod significance;
+pub mod statistics;
+pub mod temporal;
+
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::graph::undirected::{
+    UndirectedGraph,
+    approximate_betweenness::BetweennessSampling,
+    communities::{
+        context::RunContext,
+        detector::{CommunityDetector, GirvanNewman, HierarchicalGrowth, NewmanModularity},
+        partition::Partition,
+    },
+};
+
 pub struct Analyses;
 
 impl Analyses {
-    pub fn betweenness(&self) {
-        // Implement the betweenness centrality algorithm here
-        // This is a placeholder implementation
-        println!("Calculating betweenness centrality...");
+    pub fn get_detectors<T>(&self) -> Vec<Box<dyn CommunityDetector<T>>>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        /*
+         *  Conjunto padrão do `benchmark`. O Girvan–Newman recalcula o betweenness a cada aresta
+         *  removida, então entra amostrado; o `Benchmark` ainda limita o tempo de cada linha.
+         */
+        vec![
+            Box::new(GirvanNewman {
+                sampling: Some(BetweennessSampling::Pivots {
+                    samples: 32,
+                    seed: 0,
+                }),
+                ..Default::default()
+            }),
+            Box::new(HierarchicalGrowth::default()),
+            Box::new(NewmanModularity::default()),
+        ]
+    }
+
+    pub fn get_detector<T>(&self, name: &str) -> Option<Box<dyn CommunityDetector<T>>>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        // Pelo nome, o Girvan–Newman é o exato
+        if name == "betweenness" {
+            return Some(Box::new(GirvanNewman::default()));
+        }
+        self.get_detectors()
+            .into_iter()
+            .find(|detector| detector.name() == name)
     }
 
-    pub fn hierarchical_growth(&self) {
-        // Implement the hierarchical growth algorithm here
-        // This is a placeholder implementation
-        println!("Performing hierarchical growth...");
+    pub fn detect<T, D>(
+        &self,
+        graph: &UndirectedGraph<T>,
+        detector: &D,
+        context: &RunContext,
+    ) -> Partition<T>
+    where
+        D: CommunityDetector<T> + ?Sized,
+    {
+        detector.detect_with(graph, context)
     }
-}
\ No newline at end of file
+}
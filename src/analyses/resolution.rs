@@ -0,0 +1,138 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::evaluation::Evaluation;
+use crate::graph::undirected::{
+    UndirectedGraph,
+    communities::{context::RunContext, detector::CommunityDetector, partition::Partition},
+};
+
+#[derive(Debug, Clone)]
+pub struct ResolutionStep<T> {
+    pub resolution: f64,
+    pub partition: Partition<T>,
+}
+
+// Intervalo de resoluções consecutivas cujas partições são praticamente iguais
+#[derive(Debug, Clone, Copy)]
+pub struct StableRange {
+    pub from: f64,
+    pub to: f64,
+    pub steps: usize,
+    // Índice em `ResolutionScan::steps` da primeira partição do intervalo
+    pub first_step: usize,
+    pub communities: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ResolutionScan<T> {
+    pub steps: Vec<ResolutionStep<T>>,
+    pub stable_ranges: Vec<StableRange>,
+}
+
+impl<T> ResolutionScan<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    /*
+     *  Roda o detector criado por `make_detector` para cada resolução e agrupa resoluções
+     *  vizinhas cujas partições têm NMI >= `similarity`. Os intervalos com mais de um passo
+     *  são devolvidos do mais largo para o mais estreito.
+     */
+    pub fn run(
+        graph: &UndirectedGraph<T>,
+        resolutions: &[f64],
+        make_detector: impl Fn(f64) -> Box<dyn CommunityDetector<T>>,
+        similarity: f64,
+        context: &RunContext,
+    ) -> Self {
+        let steps: Vec<ResolutionStep<T>> = resolutions
+            .iter()
+            .map(|resolution| ResolutionStep {
+                resolution: *resolution,
+                partition: make_detector(*resolution).detect_with(graph, context),
+            })
+            .collect();
+
+        let mut stable_ranges: Vec<StableRange> = Vec::new();
+        let mut first_step = 0;
+        for i in 1..=steps.len() {
+            let same = i < steps.len()
+                && Evaluation::compare(
+                    &steps[i - 1].partition.communities,
+                    &steps[i].partition.communities,
+                )
+                .nmi >= similarity;
+
+            if same {
+                continue;
+            }
+
+            if i - first_step > 1 {
+                stable_ranges.push(StableRange {
+                    from: steps[first_step].resolution,
+                    to: steps[i - 1].resolution,
+                    steps: i - first_step,
+                    first_step,
+                    communities: steps[first_step].partition.len(),
+                });
+            }
+            first_step = i;
+        }
+
+        stable_ranges.sort_by(|x, y| (y.to - y.from).total_cmp(&(x.to - x.from)));
+
+        Self {
+            steps,
+            stable_ranges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected::communities::{
+        detector::NewmanModularity, quality::QualityFunction,
+    };
+
+    #[test]
+    fn scan_finds_the_cpm_transition_of_two_cliques() {
+        // Dois K4 ligados por uma aresta: com CPM, separá-los compensa a partir de 1/16
+        let mut edges: Vec<[usize; 2]> = vec![[0, 4]];
+        for start in [0, 4] {
+            for u in start..start + 4 {
+                for v in u + 1..start + 4 {
+                    edges.push([u, v]);
+                }
+            }
+        }
+        let graph = UndirectedGraph::from(edges);
+
+        let scan = ResolutionScan::run(
+            &graph,
+            &[0.01, 0.03, 0.2, 0.3, 0.4, 0.5],
+            |resolution| {
+                Box::new(NewmanModularity {
+                    quality: QualityFunction::ConstantPotts { resolution },
+                })
+            },
+            0.99,
+            // Sem semente, os empates do guloso podem juntar a ponte 0-4 antes das cliques
+            &RunContext::default().with_seed(0),
+        );
+
+        let communities: Vec<usize> = scan.steps.iter().map(|step| step.partition.len()).collect();
+        assert_eq!(communities, vec![1, 1, 2, 2, 2, 2]);
+        assert_eq!(scan.stable_ranges.len(), 2);
+        let widest = scan.stable_ranges[0];
+        assert_eq!((widest.from, widest.to), (0.2, 0.5));
+        assert_eq!(
+            (widest.steps, widest.first_step, widest.communities),
+            (4, 2, 2)
+        );
+        assert_eq!(scan.stable_ranges[1].communities, 1);
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::graph::{
+    generators::Generators,
+    undirected::{
+        UndirectedGraph,
+        communities::{context::RunContext, detector::CommunityDetector},
+    },
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Significance {
+    pub observed: f64,
+    pub mean: f64,
+    pub standard_deviation: f64,
+    pub z_score: f64,
+    // Fração (corrigida) das aleatorizações com modularidade maior ou igual à observada
+    pub p_value: f64,
+    pub samples: Vec<f64>,
+}
+
+impl Significance {
+    /*
+     *  Roda `detector` no grafo original e em `randomisations` cópias aleatorizadas por trocas
+     *  duplas de arestas, que preservam a sequência de graus. A modularidade observada é
+     *  comparada com a distribuição nula assim obtida.
+     */
+    pub fn test<T, D>(
+        graph: &UndirectedGraph<T>,
+        detector: &D,
+        randomisations: usize,
+        swaps_per_edge: usize,
+        seed: u64,
+        context: &RunContext,
+    ) -> Self
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+        D: CommunityDetector<T> + ?Sized,
+    {
+        let partition = detector.detect_with(graph, context);
+        let observed = graph.get_modularity(partition.communities);
+
+        let samples: Vec<f64> = (0..randomisations as u64)
+            .map(|i| {
+                let randomised =
+                    Generators::double_edge_swap(graph, swaps_per_edge, seed.wrapping_add(i));
+                let partition = detector.detect_with(&randomised, context);
+                randomised.get_modularity(partition.communities)
+            })
+            .collect();
+
+        Self::from_samples(observed, samples)
+    }
+
+    pub fn from_samples(observed: f64, samples: Vec<f64>) -> Self {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n.max(1.);
+        let variance = samples.iter().map(|q| (q - mean).powi(2)).sum::<f64>() / (n - 1.).max(1.);
+        let standard_deviation = variance.sqrt();
+
+        let z_score = if standard_deviation > 0. {
+            (observed - mean) / standard_deviation
+        } else if observed > mean {
+            f64::INFINITY
+        } else {
+            0.
+        };
+
+        let at_least_observed = samples.iter().filter(|q| **q >= observed).count();
+        let p_value = (at_least_observed + 1) as f64 / (n + 1.);
+
+        Self {
+            observed,
+            mean,
+            standard_deviation,
+            z_score,
+            p_value,
+            samples,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected::communities::detector::NewmanModularity;
+
+    #[test]
+    fn from_samples_computes_z_score_and_corrected_p_value() {
+        let significance = Significance::from_samples(0.5, vec![0.1, 0.2, 0.3]);
+
+        assert!((significance.mean - 0.2).abs() < 1e-12);
+        assert!((significance.standard_deviation - 0.1).abs() < 1e-12);
+        assert!((significance.z_score - 3.).abs() < 1e-9);
+        assert!((significance.p_value - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn constant_samples_do_not_divide_by_zero() {
+        assert_eq!(
+            Significance::from_samples(0.4, vec![0.1; 4]).z_score,
+            f64::INFINITY
+        );
+        assert_eq!(Significance::from_samples(0.1, vec![0.1; 4]).z_score, 0.);
+        assert_eq!(Significance::from_samples(0.1, vec![]).p_value, 1.);
+    }
+
+    #[test]
+    fn planted_communities_are_significant() {
+        let graph = Generators::connected_caveman(5, 6).graph;
+        let context = RunContext::default().with_seed(1);
+        let significance =
+            Significance::test(&graph, &NewmanModularity::default(), 10, 5, 3, &context);
+
+        assert_eq!(significance.samples.len(), 10);
+        assert!(significance.z_score > 2.);
+        assert!((significance.p_value - 1. / 11.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    graph::undirected::{UndirectedGraph, indexed::IndexedGraph},
+    utils::{json, random::Random},
+};
+
+/*
+ *  Ajuste de lei de potência P(k) ~ k^(-exponent) para a cauda k >= min_degree, com o
+ *  estimador de máxima verossimilhança discreto aproximado de Clauset, Shalizi e Newman e
+ *  `min_degree` escolhido pela menor distância de Kolmogorov–Smirnov
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct PowerLawFit {
+    pub exponent: f64,
+    pub min_degree: usize,
+    pub tail: usize,
+    pub ks_distance: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphStatistics {
+    pub vertices: usize,
+    pub edges: usize,
+    pub density: f64,
+    pub average_degree: f64,
+    pub max_degree: usize,
+    // Pares (grau, quantidade de vértices) em ordem crescente de grau
+    pub degree_distribution: Vec<(usize, usize)>,
+    pub power_law: Option<PowerLawFit>,
+    // Tamanhos das componentes conexas em ordem decrescente
+    pub components: Vec<usize>,
+    // Calculados dentro das componentes; `sources` é None quando exatos
+    pub diameter: usize,
+    pub average_shortest_path: f64,
+    pub sources: Option<usize>,
+    pub transitivity: f64,
+    pub average_clustering: f64,
+    pub assortativity: f64,
+}
+
+impl GraphStatistics {
+    const MIN_TAIL: usize = 10;
+
+    /*
+     *  Com `samples`, diâmetro e caminho médio são estimados a partir de BFS de `samples`
+     *  fontes sorteadas com `seed` (o diâmetro estimado é um limite inferior)
+     */
+    pub fn compute<T>(graph: &UndirectedGraph<T>, samples: Option<usize>, seed: u64) -> Self
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let vertices = graph.vertices.len();
+        let edges = graph.get_total_of_edges() / 2;
+        let degrees: Vec<usize> = graph
+            .vertices
+            .iter()
+            .map(|vertex| graph.get_neighbourhood(vertex).map_or(0, Vec::len))
+            .collect();
+
+        let mut histogram: BTreeMap<usize, usize> = BTreeMap::new();
+        for degree in &degrees {
+            *histogram.entry(*degree).or_insert(0) += 1;
+        }
+
+        let mut components: Vec<usize> = graph
+            .get_communities()
+            .iter()
+            .map(|component| component.len())
+            .collect();
+        components.sort_unstable_by(|x, y| y.cmp(x));
+
+        // Uma única cópia indexada e uma única contagem de triângulos para o relatório inteiro
+        let indexed = graph.to_indexed();
+        let triangles = indexed.count_triangles();
+        let (diameter, average_shortest_path, sources) = Self::distances(&indexed, samples, seed);
+
+        Self {
+            vertices,
+            edges,
+            density: if vertices > 1 {
+                2. * edges as f64 / (vertices as f64 * (vertices as f64 - 1.))
+            } else {
+                0.
+            },
+            average_degree: 2. * edges as f64 / vertices.max(1) as f64,
+            max_degree: degrees.iter().copied().max().unwrap_or(0),
+            degree_distribution: histogram.into_iter().collect(),
+            power_law: Self::fit_power_law(&degrees),
+            components,
+            diameter,
+            average_shortest_path,
+            sources,
+            transitivity: indexed.get_transitivity(&triangles),
+            average_clustering: indexed.get_average_clustering(&triangles),
+            assortativity: Self::assortativity(graph),
+        }
+    }
+
+    pub fn fit_power_law(degrees: &[usize]) -> Option<PowerLawFit> {
+        let mut degrees: Vec<usize> = degrees.iter().copied().filter(|d| *d > 0).collect();
+        degrees.sort_unstable();
+        let mut candidates = degrees.clone();
+        candidates.dedup();
+
+        let mut best: Option<PowerLawFit> = None;
+        for min_degree in candidates {
+            let tail = &degrees[degrees.partition_point(|d| *d < min_degree)..];
+            if tail.len() < Self::MIN_TAIL {
+                break;
+            }
+
+            let shift = min_degree as f64 - 0.5;
+            let log_sum: f64 = tail.iter().map(|d| (*d as f64 / shift).ln()).sum();
+            if log_sum <= 0. {
+                continue;
+            }
+            let exponent = 1. + tail.len() as f64 / log_sum;
+
+            // Maior diferença entre a distribuição acumulada complementar empírica e a ajustada
+            let mut ks_distance: f64 = 0.;
+            for (i, degree) in tail.iter().enumerate() {
+                if i > 0 && tail[i - 1] == *degree {
+                    continue;
+                }
+                let empirical = (tail.len() - i) as f64 / tail.len() as f64;
+                let fitted = ((*degree as f64 - 0.5) / shift).powf(1. - exponent);
+                ks_distance = ks_distance.max((empirical - fitted).abs());
+            }
+
+            if best.is_none_or(|best| ks_distance < best.ks_distance) {
+                best = Some(PowerLawFit {
+                    exponent,
+                    min_degree,
+                    tail: tail.len(),
+                    ks_distance,
+                });
+            }
+        }
+
+        best
+    }
+
+    fn distances<T>(
+        indexed: &IndexedGraph<T>,
+        samples: Option<usize>,
+        seed: u64,
+    ) -> (usize, f64, Option<usize>)
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let mut sources: Vec<usize> = (0..indexed.len()).collect();
+        let sampled = samples.filter(|samples| *samples < indexed.len());
+        if let Some(samples) = sampled {
+            Random::new(seed).shuffle(&mut sources);
+            sources.truncate(samples);
+        }
+
+        let (diameter, sum, pairs) = sources
+            .par_iter()
+            .map(|source| {
+                let dag = indexed.shortest_paths_dag(*source);
+                let distances = dag.order[1..].iter().map(|vertex| dag.distance[*vertex]);
+                (
+                    distances.clone().max().unwrap_or(0),
+                    distances.sum::<usize>(),
+                    dag.order.len() - 1,
+                )
+            })
+            .reduce(|| (0, 0, 0), |x, y| (x.0.max(y.0), x.1 + y.1, x.2 + y.2));
+
+        let average = if pairs > 0 {
+            sum as f64 / pairs as f64
+        } else {
+            0.
+        };
+        (diameter, average, sampled)
+    }
+
+    // Coeficiente de correlação de Pearson entre os graus das pontas de cada aresta (Newman)
+    fn assortativity<T>(graph: &UndirectedGraph<T>) -> f64
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let degree = |vertex: &T| graph.get_neighbourhood(vertex).map_or(0, Vec::len) as f64;
+        let (mut product, mut sum, mut squares, mut count) = (0., 0., 0., 0.);
+
+        // Cada aresta aparece nos dois sentidos, o que já simetriza as somas
+        for (vertex, neighbourhood) in &graph.adjacency {
+            let j = degree(vertex);
+            for neighbour in neighbourhood {
+                let k = degree(neighbour);
+                product += j * k;
+                sum += j;
+                squares += j * j;
+                count += 1.;
+            }
+        }
+
+        if count == 0. {
+            return 0.;
+        }
+        let mean = sum / count;
+        let variance = squares / count - mean * mean;
+        if variance <= 0. {
+            return 0.;
+        }
+        (product / count - mean * mean) / variance
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        text += &format!("Vertices: {}\n", self.vertices);
+        text += &format!("Edges: {}\n", self.edges);
+        text += &format!("Density: {}\n", self.density);
+        text += &format!("Average degree: {}\n", self.average_degree);
+        text += &format!("Max degree: {}\n", self.max_degree);
+        match &self.power_law {
+            Some(fit) => {
+                text += &format!(
+                    "Power-law fit: exponent {:.4} for degree >= {} ({} vertices, KS {:.4})\n",
+                    fit.exponent, fit.min_degree, fit.tail, fit.ks_distance
+                )
+            }
+            None => text += "Power-law fit: not enough vertices\n",
+        }
+        text += &format!("Connected components: {}\n", self.components.len());
+        text += &format!(
+            "Largest component: {}\n",
+            self.components.first().copied().unwrap_or(0)
+        );
+        let estimated = match self.sources {
+            Some(sources) => format!(" (estimated from {} sources)", sources),
+            None => String::new(),
+        };
+        text += &format!("Diameter: {}{}\n", self.diameter, estimated);
+        text += &format!(
+            "Average shortest path: {}{}\n",
+            self.average_shortest_path, estimated
+        );
+        text += &format!("Transitivity: {}\n", self.transitivity);
+        text += &format!(
+            "Average clustering coefficient: {}\n",
+            self.average_clustering
+        );
+        text += &format!("Degree assortativity: {}\n", self.assortativity);
+        text += "Degree distribution:\n";
+        for (degree, count) in &self.degree_distribution {
+            text += &format!("  {} {}\n", degree, count);
+        }
+        text
+    }
+
+    pub fn to_json(&self) -> String {
+        let power_law = match &self.power_law {
+            Some(fit) => json::object([
+                ("exponent", json::number(fit.exponent)),
+                ("min_degree", fit.min_degree.to_string()),
+                ("tail", fit.tail.to_string()),
+                ("ks_distance", json::number(fit.ks_distance)),
+            ]),
+            None => String::from("null"),
+        };
+        let distribution = self.degree_distribution.iter().map(|(degree, count)| {
+            json::object([("degree", degree.to_string()), ("count", count.to_string())])
+        });
+
+        json::object([
+            ("vertices", self.vertices.to_string()),
+            ("edges", self.edges.to_string()),
+            ("density", json::number(self.density)),
+            ("average_degree", json::number(self.average_degree)),
+            ("max_degree", self.max_degree.to_string()),
+            ("degree_distribution", json::array(distribution)),
+            ("power_law", power_law),
+            (
+                "components",
+                json::array(self.components.iter().map(|size| size.to_string())),
+            ),
+            ("diameter", self.diameter.to_string()),
+            (
+                "average_shortest_path",
+                json::number(self.average_shortest_path),
+            ),
+            (
+                "sources",
+                self.sources
+                    .map_or(String::from("null"), |sources| sources.to_string()),
+            ),
+            ("transitivity", json::number(self.transitivity)),
+            ("average_clustering", json::number(self.average_clustering)),
+            ("assortativity", json::number(self.assortativity)),
+        ]) + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(x: f64, y: f64) {
+        assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+    }
+
+    #[test]
+    fn path_with_a_separate_triangle() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3], [4, 5], [5, 6], [6, 4]]);
+        let statistics = GraphStatistics::compute(&graph, None, 0);
+
+        assert_eq!((statistics.vertices, statistics.edges), (7, 6));
+        assert_close(statistics.density, 12. / 42.);
+        assert_eq!(statistics.max_degree, 2);
+        assert_eq!(statistics.degree_distribution, vec![(1, 2), (2, 5)]);
+        assert_eq!(statistics.components, vec![4, 3]);
+        assert_eq!(statistics.diameter, 3);
+        // Pares ordenados: o caminho soma 20 em 12 pares e o triângulo 6 em 6
+        assert_close(statistics.average_shortest_path, (20. + 6.) / (12. + 6.));
+        assert_eq!(statistics.sources, None);
+        // 3 triplas fechadas pelo triângulo entre 5: as do triângulo e as centradas em 1 e 2
+        assert_close(statistics.transitivity, 3. / (3. + 2.));
+        assert!(statistics.power_law.is_none());
+    }
+
+    #[test]
+    fn stars_are_disassortative() {
+        let star = UndirectedGraph::from(vec![[0, 1], [0, 2], [0, 3], [0, 4]]);
+        let statistics = GraphStatistics::compute(&star, Some(2), 3);
+
+        assert_close(statistics.assortativity, -1.);
+        assert_eq!(statistics.sources, Some(2));
+        assert!(statistics.diameter <= 2);
+    }
+
+    #[test]
+    fn power_law_fit_recovers_the_exponent() {
+        // Graus com P(k) ∝ k^-2.5 em quantidades proporcionais
+        let degrees: Vec<usize> = (1..200usize)
+            .flat_map(|k| std::iter::repeat_n(k, (1e5 * (k as f64).powf(-2.5)) as usize))
+            .collect();
+        let fit = GraphStatistics::fit_power_law(&degrees).unwrap();
+
+        assert!((fit.exponent - 2.5).abs() < 0.2, "{}", fit.exponent);
+        assert!(fit.ks_distance < 0.05);
+    }
+}
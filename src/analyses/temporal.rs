@@ -0,0 +1,449 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::{
+    graph::undirected::{
+        Community, UndirectedGraph,
+        communities::{context::RunContext, detector::CommunityDetector, partition::Partition},
+    },
+    utils::json,
+};
+
+#[derive(Debug, Clone)]
+pub struct Snapshot<T> {
+    // Janela semiaberta [start, end)
+    pub start: f64,
+    pub end: f64,
+    pub graph: UndirectedGraph<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Birth,
+    Death,
+    Growth,
+    Contraction,
+    // Correspondência um-para-um sem mudança de tamanho
+    Continuation,
+    Merge,
+    Split,
+}
+
+impl Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Birth => "birth",
+            Self::Death => "death",
+            Self::Growth => "growth",
+            Self::Contraction => "contraction",
+            Self::Continuation => "continuation",
+            Self::Merge => "merge",
+            Self::Split => "split",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/*
+ *  Evento entre os snapshots `snapshot - 1` e `snapshot`. `from` são índices das comunidades
+ *  do snapshot anterior e `to` do snapshot atual.
+ */
+#[derive(Debug, Clone)]
+pub struct CommunityEvent {
+    pub snapshot: usize,
+    pub kind: EventKind,
+    pub from: Vec<usize>,
+    pub to: Vec<usize>,
+    pub size_before: usize,
+    pub size_after: usize,
+    // Maior Jaccard entre as comunidades pareadas; zero para nascimentos e mortes
+    pub jaccard: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotSummary {
+    pub start: f64,
+    pub end: f64,
+    pub vertices: usize,
+    pub edges: usize,
+    pub communities: usize,
+    pub modularity: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Timeline<T> {
+    pub snapshots: Vec<SnapshotSummary>,
+    pub partitions: Vec<Partition<T>>,
+    pub events: Vec<CommunityEvent>,
+}
+
+pub struct Temporal;
+
+impl Temporal {
+    /*
+     *  Fatia as arestas em janelas de largura `window` começando no menor tempo e avançando
+     *  `step` a cada snapshot (`step == window` gera janelas disjuntas)
+     */
+    pub fn snapshots<T>(edges: &[([T; 2], f64)], window: f64, step: f64) -> Vec<Snapshot<T>>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        if window <= 0. || step <= 0. {
+            panic!("ERROR: WINDOW AND STEP MUST BE POSITIVE");
+        }
+
+        let mut sorted: Vec<&([T; 2], f64)> = edges.iter().collect();
+        sorted.sort_by(|x, y| x.1.total_cmp(&y.1));
+        let (Some(first), Some(last)) = (sorted.first(), sorted.last()) else {
+            return vec![];
+        };
+        let (first, last) = (first.1, last.1);
+
+        let mut snapshots = Vec::new();
+        let mut k = 0;
+        loop {
+            let start = first + k as f64 * step;
+            if start > last {
+                break;
+            }
+            let end = start + window;
+
+            let begin = sorted.partition_point(|edge| edge.1 < start);
+            let finish = sorted.partition_point(|edge| edge.1 < end);
+            let mut graph = UndirectedGraph::new();
+            for ([from, to], _) in &sorted[begin..finish] {
+                if from != to {
+                    graph.push_undirected_edge(from.clone(), to.clone());
+                }
+            }
+
+            snapshots.push(Snapshot { start, end, graph });
+            k += 1;
+        }
+
+        snapshots
+    }
+
+    pub fn track<T>(
+        snapshots: &[Snapshot<T>],
+        detector: &dyn CommunityDetector<T>,
+        threshold: f64,
+        context: &RunContext,
+    ) -> Timeline<T>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let mut timeline = Timeline {
+            snapshots: Vec::new(),
+            partitions: Vec::new(),
+            events: Vec::new(),
+        };
+
+        for (i, snapshot) in snapshots.iter().enumerate() {
+            let mut partition = if snapshot.graph.has_edges() {
+                detector.detect_with(&snapshot.graph, context)
+            } else {
+                Partition::default()
+            };
+            // Índices estáveis para que os eventos apontem sempre para as mesmas comunidades
+            partition.normalize();
+
+            timeline.snapshots.push(SnapshotSummary {
+                start: snapshot.start,
+                end: snapshot.end,
+                vertices: snapshot.graph.vertices.len(),
+                edges: snapshot.graph.get_total_of_edges() / 2,
+                communities: partition.len(),
+                modularity: if snapshot.graph.has_edges() {
+                    snapshot.graph.get_modularity(partition.communities.clone())
+                } else {
+                    0.
+                },
+            });
+
+            if let Some(previous) = timeline.partitions.last() {
+                timeline.events.extend(Self::match_communities(
+                    &previous.communities,
+                    &partition.communities,
+                    threshold,
+                    i,
+                ));
+            }
+            timeline.partitions.push(partition);
+        }
+
+        timeline
+    }
+
+    /*
+     *  Pareia as comunidades de dois snapshots consecutivos cujo Jaccard é pelo menos
+     *  `threshold` e classifica o resultado: sem par é nascimento ou morte, vários
+     *  antecessores é fusão, vários sucessores é divisão e um-para-um é crescimento,
+     *  contração ou continuação conforme o tamanho
+     */
+    pub fn match_communities<T>(
+        previous: &[Community<T>],
+        current: &[Community<T>],
+        threshold: f64,
+        snapshot: usize,
+    ) -> Vec<CommunityEvent>
+    where
+        T: Eq + Hash,
+    {
+        let mut membership: HashMap<&T, usize> = HashMap::new();
+        for (j, community) in current.iter().enumerate() {
+            for vertex in community {
+                membership.insert(vertex, j);
+            }
+        }
+
+        let mut jaccard: HashMap<(usize, usize), f64> = HashMap::new();
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); previous.len()];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); current.len()];
+        for (i, community) in previous.iter().enumerate() {
+            let mut intersections: HashMap<usize, usize> = HashMap::new();
+            for vertex in community {
+                if let Some(j) = membership.get(vertex) {
+                    *intersections.entry(*j).or_insert(0) += 1;
+                }
+            }
+
+            let mut intersections: Vec<(usize, usize)> = intersections.into_iter().collect();
+            intersections.sort_unstable();
+            for (j, intersection) in intersections {
+                let union = community.len() + current[j].len() - intersection;
+                let value = intersection as f64 / union as f64;
+                if value >= threshold {
+                    jaccard.insert((i, j), value);
+                    successors[i].push(j);
+                    predecessors[j].push(i);
+                }
+            }
+        }
+
+        let size_of = |communities: &[Community<T>], indices: &[usize]| -> usize {
+            indices.iter().map(|index| communities[*index].len()).sum()
+        };
+        let best = |pairs: Vec<(usize, usize)>| -> f64 {
+            pairs.iter().map(|pair| jaccard[pair]).fold(0., f64::max)
+        };
+        let mut events = Vec::new();
+
+        for (i, successors_of_i) in successors.iter().enumerate() {
+            let kind = match successors_of_i.len() {
+                0 => EventKind::Death,
+                1 => continue,
+                _ => EventKind::Split,
+            };
+            events.push(CommunityEvent {
+                snapshot,
+                kind,
+                from: vec![i],
+                to: successors_of_i.clone(),
+                size_before: previous[i].len(),
+                size_after: size_of(current, successors_of_i),
+                jaccard: best(successors_of_i.iter().map(|j| (i, *j)).collect()),
+            });
+        }
+
+        for (j, predecessors_of_j) in predecessors.iter().enumerate() {
+            let kind = match predecessors_of_j[..] {
+                [] => EventKind::Birth,
+                // Um-para-um somente se o antecessor também não se dividiu
+                [i] if successors[i].len() == 1 => match previous[i].len().cmp(&current[j].len()) {
+                    std::cmp::Ordering::Less => EventKind::Growth,
+                    std::cmp::Ordering::Greater => EventKind::Contraction,
+                    std::cmp::Ordering::Equal => EventKind::Continuation,
+                },
+                [_] => continue,
+                _ => EventKind::Merge,
+            };
+            events.push(CommunityEvent {
+                snapshot,
+                kind,
+                from: predecessors_of_j.clone(),
+                to: vec![j],
+                size_before: size_of(previous, predecessors_of_j),
+                size_after: current[j].len(),
+                jaccard: best(predecessors_of_j.iter().map(|i| (*i, j)).collect()),
+            });
+        }
+
+        events
+    }
+}
+
+impl<T> Timeline<T> {
+    fn indices(indices: &[usize]) -> String {
+        indices
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<String>>()
+            .join(";")
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("snapshot,start,end,event,from,to,size_before,size_after,jaccard\n");
+        for event in &self.events {
+            let snapshot = &self.snapshots[event.snapshot];
+            csv += &format!(
+                "{},{},{},{},{},{},{},{},{:.6}\n",
+                event.snapshot,
+                snapshot.start,
+                snapshot.end,
+                event.kind,
+                Self::indices(&event.from),
+                Self::indices(&event.to),
+                event.size_before,
+                event.size_after,
+                event.jaccard
+            );
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        let indices = |indices: &[usize]| json::array(indices.iter().map(|i| i.to_string()));
+
+        let snapshots = self.snapshots.iter().enumerate().map(|(i, snapshot)| {
+            json::object([
+                ("snapshot", i.to_string()),
+                ("start", json::number(snapshot.start)),
+                ("end", json::number(snapshot.end)),
+                ("vertices", snapshot.vertices.to_string()),
+                ("edges", snapshot.edges.to_string()),
+                ("communities", snapshot.communities.to_string()),
+                ("modularity", json::number(snapshot.modularity)),
+            ])
+        });
+        let events = self.events.iter().map(|event| {
+            json::object([
+                ("snapshot", event.snapshot.to_string()),
+                ("event", json::string(&event.kind.to_string())),
+                ("from", indices(&event.from)),
+                ("to", indices(&event.to)),
+                ("size_before", event.size_before.to_string()),
+                ("size_after", event.size_after.to_string()),
+                ("jaccard", json::number(event.jaccard)),
+            ])
+        });
+
+        json::object([
+            ("snapshots", json::array(snapshots)),
+            ("events", json::array(events)),
+        ]) + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected::communities::detector::NewmanModularity;
+
+    fn communities(communities: &[&[usize]]) -> Vec<Community<usize>> {
+        communities
+            .iter()
+            .map(|community| community.iter().copied().collect())
+            .collect()
+    }
+
+    fn kinds(events: &[CommunityEvent]) -> Vec<EventKind> {
+        events.iter().map(|event| event.kind).collect()
+    }
+
+    #[test]
+    fn births_and_deaths_have_no_partner() {
+        let events = Temporal::match_communities(
+            &communities(&[&[0, 1, 2]]),
+            &communities(&[&[5, 6]]),
+            0.3,
+            1,
+        );
+
+        assert_eq!(kinds(&events), vec![EventKind::Death, EventKind::Birth]);
+        assert!(events.iter().all(|event| event.jaccard == 0.));
+    }
+
+    #[test]
+    fn merges_and_splits() {
+        let halves = communities(&[&[0, 1, 2, 3], &[4, 5, 6, 7]]);
+        let whole = communities(&[&[0, 1, 2, 3, 4, 5, 6, 7]]);
+
+        let merge = Temporal::match_communities(&halves, &whole, 0.3, 1);
+        assert_eq!(kinds(&merge), vec![EventKind::Merge]);
+        assert_eq!(
+            (merge[0].from.clone(), merge[0].to.clone()),
+            (vec![0, 1], vec![0])
+        );
+        assert_eq!((merge[0].size_before, merge[0].size_after), (8, 8));
+        assert_eq!(merge[0].jaccard, 0.5);
+
+        let split = Temporal::match_communities(&whole, &halves, 0.3, 2);
+        assert_eq!(kinds(&split), vec![EventKind::Split]);
+        assert_eq!(
+            (split[0].from.clone(), split[0].to.clone()),
+            (vec![0], vec![0, 1])
+        );
+
+        // Abaixo do limiar as metades não são parceiras da comunidade inteira
+        let unmatched = Temporal::match_communities(&halves, &whole, 0.6, 1);
+        assert_eq!(
+            kinds(&unmatched),
+            vec![EventKind::Death, EventKind::Death, EventKind::Birth]
+        );
+    }
+
+    #[test]
+    fn one_to_one_matches_compare_sizes() {
+        let small = communities(&[&[0, 1, 2]]);
+        let large = communities(&[&[0, 1, 2, 3]]);
+
+        assert_eq!(
+            kinds(&Temporal::match_communities(&small, &large, 0.3, 1)),
+            vec![EventKind::Growth]
+        );
+        assert_eq!(
+            kinds(&Temporal::match_communities(&large, &small, 0.3, 1)),
+            vec![EventKind::Contraction]
+        );
+        assert_eq!(
+            kinds(&Temporal::match_communities(&small, &small, 0.3, 1)),
+            vec![EventKind::Continuation]
+        );
+    }
+
+    #[test]
+    fn tracked_windows_report_a_merge() {
+        let mut edges: Vec<([usize; 2], f64)> = Vec::new();
+        // Janela 0: dois triângulos separados; janela 1: os seis vértices num K6
+        for [u, v] in [[0, 1], [1, 2], [2, 0], [3, 4], [4, 5], [5, 3]] {
+            edges.push(([u, v], 0.5));
+        }
+        for u in 0..6 {
+            for v in u + 1..6 {
+                edges.push(([u, v], 1.5));
+            }
+        }
+
+        let snapshots = Temporal::snapshots(&edges, 1., 1.);
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!((snapshots[1].start, snapshots[1].end), (1.5, 2.5));
+
+        let timeline = Temporal::track(
+            &snapshots,
+            &NewmanModularity::default(),
+            0.3,
+            &RunContext::default(),
+        );
+        assert_eq!(timeline.snapshots[0].edges, 6);
+        assert_eq!(timeline.snapshots[1].edges, 15);
+        assert_eq!(timeline.partitions[0].len(), 2);
+        assert_eq!(kinds(&timeline.events), vec![EventKind::Merge]);
+        assert_eq!(timeline.events[0].snapshot, 1);
+        assert_eq!(timeline.to_csv().lines().count(), 2);
+    }
+}
@@ -0,0 +1,1001 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    fs,
+    hash::Hash,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    analyses::{
+        Analyses,
+        benchmark::{Benchmark, BenchmarkCase},
+        evaluation::Evaluation,
+        resolution::ResolutionScan,
+        significance::Significance,
+        statistics::GraphStatistics,
+        temporal::Temporal,
+    },
+    file::{File, GraphFormat, PartitionFormat},
+    graph::{
+        generators::{Generators, PlantedGraph, planted::LfrParameters},
+        interner::Interner,
+        undirected::{
+            UndirectedGraph,
+            approximate_betweenness::BetweennessSampling,
+            builder::InternedBuilder,
+            communities::{
+                context::{Budget, RunContext},
+                detector::{
+                    CommunityDetector, GirvanNewman, HierarchicalGrowth, NewmanModularity,
+                    SplitAtBridges,
+                },
+                observer::{Logger, Observer, Silent},
+                quality::QualityFunction,
+            },
+        },
+        utils::Utils,
+    },
+};
+
+pub const USAGE: &str = "\
+USAGE:
+    projeto_2_rs <COMMAND> [OPTIONS]
+
+COMMANDS:
+    detect    --algorithm <name> --input <file> [--format <fmt>] [--output <dir>]
+              [--quiet <bool>] [--max-iterations <n>] [--time-limit <secs>]
+              [--seed <u64>] [--alpha <f64>] [--quality modularity|cpm]
+              [--resolution <f64>] [--sampling pivots|adaptive] [--samples <n>]
+              [--epsilon <f64>] [--delta <f64>]; --sampling makes betweenness use
+              approximate edge betweenness; --split-bridges true runs the algorithm
+              separately on each piece left after removing every bridge
+    scan      --algorithm <name> --input <file> --resolutions <from:to:step|a,b,...>
+              [--similarity <nmi>] plus the detect options
+              algorithms: betweenness, hierarchical_growth, newmans_modularity_clustering
+    significance  --algorithm <name> --input <file> [--samples <n>] [--swaps <n>]
+              plus the detect options; each sample is a degree-preserving rewiring
+              with <swaps> double-edge swaps per edge
+    temporal  --algorithm <name> --input <file> --window <f64> [--step <f64>]
+              [--threshold <jaccard>] [--csv <file>] [--json <file>] plus the detect
+              options; each input line is `u v time`, and communities of consecutive
+              windows are matched by Jaccard overlap; next to the --csv (or --json)
+              file, <name>_window_<i>.txt gets the `vertex community` lines of window i
+    centrality --measure <name> --input <file> [--format <fmt>] [--normalized <bool>]
+              [--top <n>] [--output <file>] [--alpha <f64>] [--beta <f64>]
+              [--damping <f64>] [--personalization <vertex,...>] [--sampling <mode>]
+              measures: degree, closeness, harmonic, betweenness, eigenvector, katz,
+              pagerank
+    cores     --input <file> [--format <fmt>] [--k <n> --output <file> [--to <fmt>]]
+              [--numbers <file>] [--truss <file>]; --output writes the k-core, --numbers
+              the core number of each vertex and --truss the truss number of each edge
+    bridges   --input <file> [--format <fmt>] [--output <file>]; reports bridges,
+              articulation points and biconnected components, and --output writes the
+              biconnected components, one per line
+    path      --input <file> --from <vertex> --to <vertex> [--format <fmt>] [--all <bool>]
+    extract   --input <file> --output <file> [--format <fmt>] [--to <fmt>]
+              (--ego <vertex> [--radius <n>] | --partition <file> --community <i>
+              [--partition-format <pfmt>] | --vertices <vertex,...>); writes the induced
+              subgraph, and --to adjlist keeps the vertices left without edges
+    quotient  --input <file> --partition <file> [--partition-format <pfmt>]
+              [--format <fmt>] [--output <file>]; --output writes `i j weight` lines,
+              with `i i weight` for the edges inside community i
+    stats     --input <file> [--format <fmt>] [--json <file>] [--samples <n>] [--seed <u64>]
+              with --samples, diameter and average shortest path are estimated from
+              BFS of <n> random sources
+    evaluate  --partition <file> --truth <file> [--partition-format <pfmt>]
+              [--truth-format <pfmt>] [--input <file> --format <fmt>]
+    benchmark [--inputs <file,...> [--truths <file,...>] [--format <fmt>]]
+              [--mixings <f64,...>] [--algorithms <name,...>] [--csv <file>]
+              [--markdown <file>] plus the detect and lfr options; without --inputs,
+              one LFR graph is generated per mixing parameter; the default betweenness
+              samples 32 pivots, and each row stops after 60 s unless --time-limit is
+              given (stopped rows are reported as truncated); peak memory is not
+              measured in builds with `--no-default-features`
+    convert   --input <file> --output <file> [--format <fmt>] [--to <fmt>]
+    generate  --model <name> --output <file> [--truth <file>] [--to <fmt>] [--seed <u64>]
+              connected_caveman, relaxed_caveman: --cliques <n> --size <n> [--probability <f64>]
+              planted_partition: --groups <n> --size <n> --p-in <f64> --p-out <f64>
+              lfr: [--n <n>] [--average-degree <f64>] [--max-degree <n>] [--mixing <f64>]
+                   [--degree-exponent <f64>] [--community-exponent <f64>]
+                   [--min-community <n>] [--max-community <n>]
+              gnp: --n <n> --p <f64>    gnm: --n <n> --m <n>
+              barabasi_albert: --n <n> --m <n>    watts_strogatz: --n <n> --k <n> --p <f64>
+              configuration_model: --input <file> [--format <fmt>]
+
+FORMATS:
+    <fmt>     edgelist (default), csv, adjlist
+    <pfmt>    communities (default), membership
+
+INPUT:
+    --cache true on any command that reads --input or --inputs keeps a binary copy
+    in <input>.<fmt>.bin, memory-mapped on later runs while it is newer than the text
+    file; --stream true parses it in parallel chunks of --chunk-size bytes (default
+    8 MiB) and reports throughput and skipped lines on stderr";
+
+pub struct Cli {
+    pub command: String,
+    pub options: HashMap<String, String>,
+}
+
+impl Cli {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let command = args.next().ok_or("MISSING COMMAND")?;
+        let mut options = HashMap::new();
+
+        while let Some(arg) = args.next() {
+            let key = arg
+                .strip_prefix("--")
+                .ok_or(format!("UNEXPECTED ARGUMENT: {}", arg))?;
+            let value = args.next().ok_or(format!("MISSING VALUE FOR --{}", key))?;
+            options.insert(key.to_string(), value);
+        }
+
+        Ok(Self { command, options })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        match self.command.as_str() {
+            "detect" => self.detect(),
+            "scan" => self.scan(),
+            "significance" => self.significance(),
+            "temporal" => self.temporal(),
+            "centrality" => self.centrality(),
+            "cores" => self.cores(),
+            "bridges" => self.bridges(),
+            "path" => self.path(),
+            "extract" => self.extract(),
+            "quotient" => self.quotient(),
+            "stats" => self.stats(),
+            "evaluate" => self.evaluate(),
+            "benchmark" => self.benchmark(),
+            "convert" => self.convert(),
+            "generate" => self.generate(),
+            "help" | "--help" | "-h" => {
+                println!("{}", USAGE);
+                Ok(())
+            }
+            command => Err(format!("UNKNOWN COMMAND: {}", command)),
+        }
+    }
+
+    fn required(&self, key: &str) -> Result<&String, String> {
+        self.options
+            .get(key)
+            .ok_or(format!("MISSING REQUIRED OPTION --{}", key))
+    }
+
+    fn optional<V: FromStr>(&self, key: &str, default: V) -> Result<V, String> {
+        Ok(self.optional_value(key)?.unwrap_or(default))
+    }
+
+    fn optional_value<V: FromStr>(&self, key: &str) -> Result<Option<V>, String> {
+        self.options
+            .get(key)
+            .map(|value| {
+                value
+                    .parse::<V>()
+                    .map_err(|_| format!("INVALID VALUE FOR --{}: {}", key, value))
+            })
+            .transpose()
+    }
+
+    /*
+     *  Os algoritmos rodam sobre identificadores; os rótulos voltam só na saída. Os rótulos são
+     *  internados durante a leitura, então o grafo com rótulos nunca é montado.
+     */
+    fn read_interned_graph(&self) -> Result<(Interner<String>, UndirectedGraph<usize>), String> {
+        self.read_interned(self.required("input")?)
+    }
+
+    fn read_interned(
+        &self,
+        input: &str,
+    ) -> Result<(Interner<String>, UndirectedGraph<usize>), String> {
+        let format = self.optional("format", GraphFormat::EdgeList)?;
+
+        if self.optional("cache", false)? {
+            return File::read_cached_interned::<String>(input, format);
+        }
+        if self.optional("stream", false)? {
+            let chunk_size = self.optional("chunk-size", File::DEFAULT_CHUNK_SIZE)?;
+            let (interner, graph, report) =
+                File::read_streaming_interned::<String>(input, format, chunk_size)?;
+            eprintln!("{}", report);
+            return Ok((interner, graph));
+        }
+        let mut builder = InternedBuilder::new();
+        builder.extend(File::read_with_format::<String>(input, format)?);
+        Ok(builder.build())
+    }
+
+    fn detector<T>(&self) -> Result<Box<dyn CommunityDetector<T>>, String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + 'static,
+    {
+        let detector = self.detector_named(self.required("algorithm")?)?;
+
+        if self.optional("split-bridges", false)? {
+            return Ok(Box::new(SplitAtBridges::new(detector)));
+        }
+        Ok(detector)
+    }
+
+    fn detector_named<T>(&self, name: &str) -> Result<Box<dyn CommunityDetector<T>>, String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        match name {
+            "betweenness" => Ok(Box::new(GirvanNewman {
+                quality: self.quality()?,
+                sampling: self.sampling()?,
+            })),
+            "hierarchical_growth" => Ok(Box::new(HierarchicalGrowth {
+                alpha: self.optional("alpha", 1.)?,
+            })),
+            "newmans_modularity_clustering" => Ok(Box::new(NewmanModularity {
+                quality: self.quality()?,
+            })),
+            name => Analyses
+                .get_detector(name)
+                .ok_or(format!("UNKNOWN ALGORITHM: {}", name)),
+        }
+    }
+
+    fn quality(&self) -> Result<QualityFunction, String> {
+        let resolution = self.optional("resolution", 1.)?;
+
+        match self
+            .optional("quality", String::from("modularity"))?
+            .as_str()
+        {
+            "modularity" => Ok(QualityFunction::Modularity { resolution }),
+            "cpm" => Ok(QualityFunction::ConstantPotts { resolution }),
+            quality => Err(format!("UNKNOWN QUALITY FUNCTION: {}", quality)),
+        }
+    }
+
+    fn sampling(&self) -> Result<Option<BetweennessSampling>, String> {
+        let seed = self.optional("seed", 0)?;
+
+        match self.optional_value::<String>("sampling")?.as_deref() {
+            None => Ok(None),
+            Some("pivots") => Ok(Some(BetweennessSampling::Pivots {
+                samples: self.optional("samples", 64)?,
+                seed,
+            })),
+            Some("adaptive") => Ok(Some(BetweennessSampling::Adaptive {
+                epsilon: self.optional("epsilon", 0.05)?,
+                delta: self.optional("delta", 0.1)?,
+                seed,
+            })),
+            Some(sampling) => Err(format!("UNKNOWN SAMPLING MODE: {}", sampling)),
+        }
+    }
+
+    // Aceita uma lista `a,b,c` ou um intervalo `início:fim:passo`
+    fn resolutions(&self) -> Result<Vec<f64>, String> {
+        let value = self.required("resolutions")?;
+        let invalid = || format!("INVALID VALUE FOR --resolutions: {}", value);
+        let numbers = value
+            .split([',', ':'])
+            .map(|number| number.trim().parse::<f64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<f64>, String>>()?;
+
+        if !value.contains(':') {
+            return Ok(numbers);
+        }
+
+        match numbers[..] {
+            [from, to, step] if step > 0. => {
+                let count = ((to - from) / step + 1e-9).floor() as usize;
+                Ok((0..=count).map(|i| from + i as f64 * step).collect())
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    fn list(&self, key: &str) -> Option<Vec<String>> {
+        self.options
+            .get(key)
+            .map(|value| value.split(',').map(|s| s.trim().to_string()).collect())
+    }
+
+    fn lfr_parameters(&self) -> Result<LfrParameters, String> {
+        let default = LfrParameters::default();
+
+        Ok(LfrParameters {
+            n: self.optional("n", default.n)?,
+            average_degree: self.optional("average-degree", default.average_degree)?,
+            max_degree: self.optional("max-degree", default.max_degree)?,
+            degree_exponent: self.optional("degree-exponent", default.degree_exponent)?,
+            community_exponent: self.optional("community-exponent", default.community_exponent)?,
+            mixing: self.optional("mixing", default.mixing)?,
+            min_community: self.optional("min-community", default.min_community)?,
+            max_community: self.optional("max-community", default.max_community)?,
+        })
+    }
+
+    fn context(&self) -> Result<RunContext<'static>, String> {
+        let observer: &dyn Observer = if self.optional("quiet", false)? {
+            &Silent
+        } else {
+            &Logger
+        };
+        let budget = Budget {
+            max_iterations: self.optional_value("max-iterations")?,
+            time_limit: self
+                .optional_value("time-limit")?
+                .map(Duration::from_secs_f64),
+        };
+
+        let mut context = RunContext::new(observer).with_budget(budget);
+        context.seed = self.optional_value("seed")?;
+        Ok(context)
+    }
+
+    fn detect(&self) -> Result<(), String> {
+        let detector = self.detector::<usize>()?;
+        let (interner, graph) = self.read_interned_graph()?;
+        let start = Instant::now();
+        let context = self.context()?;
+        let partition = Analyses.detect(&graph, detector.as_ref(), &context);
+        let elapsed = start.elapsed();
+
+        let output: String = self.optional("output", detector.name().to_string())?;
+        let levels = partition
+            .hierarchy
+            .clone()
+            .unwrap_or(vec![partition.communities.clone()]);
+        for level in levels {
+            let modularity = graph.get_modularity(level.clone());
+            Utils::persist_communities(
+                interner.resolve_communities(&level),
+                format!("{}/{}_{}", output, level.len(), modularity),
+            );
+        }
+
+        println!("Algorithm: {}", detector.name());
+        println!("Communities: {}", partition.len());
+        println!("{}: {}", detector.quality(), partition.quality);
+        println!("Truncated: {}", partition.truncated);
+        println!("Time: {:?}", elapsed);
+
+        Ok(())
+    }
+
+    fn scan(&self) -> Result<(), String> {
+        let algorithm = self.required("algorithm")?.clone();
+        if !["betweenness", "newmans_modularity_clustering"].contains(&algorithm.as_str()) {
+            return Err(format!(
+                "ALGORITHM {} HAS NO RESOLUTION PARAMETER",
+                algorithm
+            ));
+        }
+
+        let (_, graph) = self.read_interned_graph()?;
+        let quality = self.quality()?;
+        let sampling = self.sampling()?;
+        let make_detector = |resolution: f64| -> Box<dyn CommunityDetector<usize>> {
+            let quality = quality.with_resolution(resolution);
+            match algorithm.as_str() {
+                "betweenness" => Box::new(GirvanNewman { quality, sampling }),
+                _ => Box::new(NewmanModularity { quality }),
+            }
+        };
+
+        let scan = ResolutionScan::run(
+            &graph,
+            &self.resolutions()?,
+            make_detector,
+            self.optional("similarity", 0.99)?,
+            &self.context()?,
+        );
+
+        println!("| resolution | communities | quality | modularity |");
+        println!("| --- | --- | --- | --- |");
+        for step in &scan.steps {
+            println!(
+                "| {:.4} | {} | {:.6} | {:.6} |",
+                step.resolution,
+                step.partition.len(),
+                step.partition.quality,
+                graph.get_modularity(step.partition.communities.clone())
+            );
+        }
+
+        println!();
+        println!("Stable ranges:");
+        for range in &scan.stable_ranges {
+            println!(
+                "  [{:.4}, {:.4}]: {} communities over {} steps",
+                range.from, range.to, range.communities, range.steps
+            );
+        }
+
+        Ok(())
+    }
+
+    fn significance(&self) -> Result<(), String> {
+        let detector = self.detector::<usize>()?;
+        let (_, graph) = self.read_interned_graph()?;
+        let significance = Significance::test(
+            &graph,
+            detector.as_ref(),
+            self.optional("samples", 20)?,
+            self.optional("swaps", 10)?,
+            self.optional("seed", 0)?,
+            &self.context()?,
+        );
+
+        println!("Algorithm: {}", detector.name());
+        println!("Observed modularity: {}", significance.observed);
+        println!(
+            "Null modularity: {} ± {}",
+            significance.mean, significance.standard_deviation
+        );
+        println!("z-score: {}", significance.z_score);
+        println!("p-value: {}", significance.p_value);
+
+        Ok(())
+    }
+
+    fn temporal(&self) -> Result<(), String> {
+        let detector = self.detector::<usize>()?;
+        let mut interner = Interner::new();
+        let mut edges: Vec<([usize; 2], f64)> = File::read_timestamped::<String>(
+            self.required("input")?,
+            self.optional("format", GraphFormat::EdgeList)?,
+        )?
+        .into_iter()
+        .map(|([from, to], time)| ([interner.intern(from), interner.intern(to)], time))
+        .collect();
+        let positions = interner.sort_stable();
+        for ([from, to], _) in &mut edges {
+            (*from, *to) = (positions[*from], positions[*to]);
+        }
+        let window: f64 = self
+            .required("window")?
+            .parse()
+            .map_err(|_| String::from("INVALID VALUE FOR --window"))?;
+        let step = self.optional("step", window)?;
+        if window <= 0. || step <= 0. {
+            return Err(String::from("--window AND --step MUST BE POSITIVE"));
+        }
+
+        let snapshots = Temporal::snapshots(&edges, window, step);
+        let timeline = Temporal::track(
+            &snapshots,
+            detector.as_ref(),
+            self.optional("threshold", 0.3)?,
+            &self.context()?,
+        );
+
+        if let Some(path) = self.options.get("csv") {
+            fs::write(path, timeline.to_csv()).map_err(|error| error.to_string())?;
+        }
+        if let Some(path) = self.options.get("json") {
+            fs::write(path, timeline.to_json()).map_err(|error| error.to_string())?;
+        }
+        // A pertinência de cada janela fica ao lado do arquivo de eventos
+        if let Some(path) = self.options.get("csv").or(self.options.get("json")) {
+            let path = Path::new(path);
+            let stem = path.file_stem().map_or(String::from("events"), |stem| {
+                stem.to_string_lossy().into_owned()
+            });
+            for (i, partition) in timeline.partitions.iter().enumerate() {
+                let window = path.with_file_name(format!("{}_window_{}.txt", stem, i));
+                File::write_membership(
+                    &interner.resolve_communities(&partition.communities),
+                    window.to_string_lossy(),
+                )?;
+            }
+        }
+
+        println!("| snapshot | start | end | vertices | edges | communities | modularity |");
+        println!("| --- | --- | --- | --- | --- | --- | --- |");
+        for (i, snapshot) in timeline.snapshots.iter().enumerate() {
+            println!(
+                "| {} | {} | {} | {} | {} | {} | {:.6} |",
+                i,
+                snapshot.start,
+                snapshot.end,
+                snapshot.vertices,
+                snapshot.edges,
+                snapshot.communities,
+                snapshot.modularity
+            );
+        }
+
+        println!();
+        println!("Events:");
+        for event in &timeline.events {
+            println!(
+                "  {}: {} {:?} -> {:?} ({} -> {} vertices)",
+                event.snapshot,
+                event.kind,
+                event.from,
+                event.to,
+                event.size_before,
+                event.size_after
+            );
+        }
+
+        Ok(())
+    }
+
+    fn benchmark(&self) -> Result<(), String> {
+        let context = self.context()?;
+
+        let report = match self.list("inputs") {
+            Some(inputs) => {
+                let truths = self.list("truths").unwrap_or_default();
+                let cases = inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, input)| {
+                        let (interner, graph) = self.read_interned(input)?;
+                        // Vértices da referência ausentes do grafo não entram na comparação
+                        let ground_truth = truths
+                            .get(i)
+                            .map(|truth| File::read_partition(truth, PartitionFormat::Communities))
+                            .transpose()?
+                            .map(|communities| interner.get_community_ids(&communities));
+                        Ok(BenchmarkCase {
+                            name: input.clone(),
+                            graph,
+                            ground_truth,
+                        })
+                    })
+                    .collect::<Result<Vec<BenchmarkCase<usize>>, String>>()?;
+                Benchmark::run(&cases, &self.benchmark_detectors()?, &context)
+            }
+            None => {
+                let mixings = self
+                    .list("mixings")
+                    .unwrap_or(vec!["0.1".into(), "0.3".into(), "0.5".into()])
+                    .iter()
+                    .map(|mixing| {
+                        mixing
+                            .parse::<f64>()
+                            .map_err(|_| format!("INVALID VALUE FOR --mixings: {}", mixing))
+                    })
+                    .collect::<Result<Vec<f64>, String>>()?;
+                let cases = Benchmark::lfr_sweep(
+                    &mixings,
+                    &self.lfr_parameters()?,
+                    self.optional("seed", 0)?,
+                );
+                Benchmark::run(&cases, &self.benchmark_detectors()?, &context)
+            }
+        };
+
+        if let Some(path) = self.options.get("csv") {
+            fs::write(path, report.to_csv()).map_err(|error| error.to_string())?;
+        }
+        if let Some(path) = self.options.get("markdown") {
+            fs::write(path, report.to_markdown()).map_err(|error| error.to_string())?;
+        }
+        print!("{}", report.to_markdown());
+
+        Ok(())
+    }
+
+    fn benchmark_detectors<T>(&self) -> Result<Vec<Box<dyn CommunityDetector<T>>>, String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        match self.list("algorithms") {
+            Some(names) => names.iter().map(|name| self.detector_named(name)).collect(),
+            None => Ok(Analyses.get_detectors()),
+        }
+    }
+
+    fn centrality(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let normalized = self.optional("normalized", true)?;
+
+        let values = match self.required("measure")?.as_str() {
+            "degree" => graph.get_degree_centrality(normalized),
+            "closeness" => graph.get_closeness_centrality(normalized),
+            "harmonic" => graph.get_harmonic_centrality(normalized),
+            "betweenness" => match self.sampling()? {
+                Some(sampling) => graph.get_approximate_vertices_betweenness(sampling, normalized),
+                None => graph.get_vertices_betweenness(normalized),
+            },
+            "eigenvector" => graph.get_eigenvector_centrality(normalized)?,
+            "katz" => graph.get_katz_centrality(
+                self.optional("alpha", 0.1)?,
+                self.optional("beta", 1.)?,
+                normalized,
+            )?,
+            "pagerank" => {
+                let personalization: Option<HashMap<usize, f64>> =
+                    self.list("personalization").map(|vertices| {
+                        vertices
+                            .iter()
+                            .filter_map(|vertex| interner.get_id(vertex))
+                            .map(|id| (id, 1.))
+                            .collect()
+                    });
+                graph.get_pagerank(
+                    self.optional("damping", 0.85)?,
+                    personalization.as_ref(),
+                    normalized,
+                )?
+            }
+            measure => return Err(format!("UNKNOWN CENTRALITY MEASURE: {}", measure)),
+        };
+
+        let mut ranking: Vec<(String, f64)> = interner.resolve_values(values).into_iter().collect();
+        ranking.sort_by(|x, y| {
+            y.1.total_cmp(&x.1)
+                .then_with(|| Utils::stable_key(&x.0).cmp(&Utils::stable_key(&y.0)))
+        });
+
+        if let Some(path) = self.options.get("output") {
+            let lines: Vec<String> = ranking
+                .iter()
+                .map(|(vertex, value)| format!("{} {}\n", vertex, value))
+                .collect();
+            fs::write(path, lines.concat()).map_err(|error| error.to_string())?;
+        }
+        for (vertex, value) in ranking.iter().take(self.optional("top", 10)?) {
+            println!("{} {}", vertex, value);
+        }
+
+        Ok(())
+    }
+
+    fn cores(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let cores = interner.resolve_values(graph.get_core_numbers());
+
+        let mut distribution: HashMap<usize, usize> = HashMap::new();
+        for core in cores.values() {
+            *distribution.entry(*core).or_insert(0) += 1;
+        }
+        let mut distribution: Vec<(usize, usize)> = distribution.into_iter().collect();
+        distribution.sort_unstable();
+
+        println!(
+            "Degeneracy: {}",
+            distribution.last().map_or(0, |(core, _)| *core)
+        );
+        println!("Core sizes:");
+        for (core, count) in &distribution {
+            println!("  {} {}", core, count);
+        }
+
+        if let Some(path) = self.options.get("numbers") {
+            let lines: Vec<String> = Utils::stable_order(cores.keys().collect(), None)
+                .iter()
+                .map(|vertex| format!("{} {}\n", vertex, cores[*vertex]))
+                .collect();
+            fs::write(path, lines.concat()).map_err(|error| error.to_string())?;
+        }
+        if let Some(path) = self.options.get("truss") {
+            let mut truss: Vec<(String, usize)> = graph
+                .get_truss_numbers()
+                .into_iter()
+                .map(|(edge, truss)| {
+                    let (from, to) = (
+                        &interner.get_labels()[edge.from],
+                        &interner.get_labels()[edge.to],
+                    );
+                    (format!("{} {}", from, to), truss)
+                })
+                .collect();
+            truss.sort_by(|x, y| y.1.cmp(&x.1).then_with(|| x.0.cmp(&y.0)));
+            let lines: Vec<String> = truss
+                .iter()
+                .map(|(edge, truss)| format!("{} {}\n", edge, truss))
+                .collect();
+            fs::write(path, lines.concat()).map_err(|error| error.to_string())?;
+        }
+        if let Some(output) = self.options.get("output") {
+            let k = self.optional("k", distribution.last().map_or(0, |(core, _)| *core))?;
+            let core = interner.resolve_graph(&graph.get_k_core(k));
+            File::write(&core, output, self.optional("to", GraphFormat::EdgeList)?)?;
+            println!(
+                "Wrote the {}-core ({} vertices, {} edges) into {}",
+                k,
+                core.vertices.len(),
+                core.get_total_of_edges() / 2,
+                output
+            );
+        }
+
+        Ok(())
+    }
+
+    fn bridges(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let labels = interner.get_labels();
+        let bridges = graph.get_bridges();
+        let articulation_points = graph.get_articulation_points();
+        let components = interner.resolve_communities(&graph.get_biconnected_components());
+
+        println!("Bridges: {}", bridges.len());
+        for bridge in &bridges {
+            println!("  {} {}", labels[bridge.from], labels[bridge.to]);
+        }
+        println!("Articulation points: {}", articulation_points.len());
+        if !articulation_points.is_empty() {
+            let points: Vec<&str> = articulation_points
+                .iter()
+                .map(|vertex| labels[*vertex].as_str())
+                .collect();
+            println!("  {}", points.join(" "));
+        }
+        println!("Biconnected components: {}", components.len());
+        println!(
+            "Pieces after removing bridges: {}",
+            graph.split_at_bridges().len()
+        );
+
+        if let Some(path) = self.options.get("output") {
+            File::write_partition(&components, path)?;
+        }
+
+        Ok(())
+    }
+
+    fn path(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let (from, to) = (self.required("from")?, self.required("to")?);
+
+        let paths = match (interner.get_id(from), interner.get_id(to)) {
+            (Some(source), Some(target)) if self.optional("all", false)? => {
+                graph.get_all_shortest_paths(&source, &target)
+            }
+            (Some(source), Some(target)) => graph
+                .get_shortest_path(&source, &target)
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        };
+        let Some(first) = paths.first() else {
+            return Err(format!("NO PATH FROM {} TO {}", from, to));
+        };
+
+        println!("Distance: {}", first.len() - 1);
+        println!("Paths: {}", paths.len());
+        for path in &paths {
+            let labels: Vec<&str> = path
+                .iter()
+                .map(|vertex| interner.get_labels()[*vertex].as_str())
+                .collect();
+            println!("  {}", labels.join(" "));
+        }
+
+        Ok(())
+    }
+
+    fn extract(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let output = self.required("output")?;
+
+        let subgraph = if let Some(center) = self.options.get("ego") {
+            match interner.get_id(center) {
+                Some(center) => graph.get_ego_network(&center, self.optional("radius", 1)?),
+                None => UndirectedGraph::new(),
+            }
+        } else if let Some(path) = self.options.get("partition") {
+            let mut communities = interner.get_community_ids(&File::read_partition(
+                path,
+                self.optional("partition-format", PartitionFormat::Communities)?,
+            )?);
+            let index: usize = self
+                .required("community")?
+                .parse()
+                .map_err(|_| String::from("INVALID VALUE FOR --community"))?;
+            if index >= communities.len() {
+                return Err(format!(
+                    "THE PARTITION HAS ONLY {} COMMUNITIES",
+                    communities.len()
+                ));
+            }
+            graph.get_community_subgraph(&communities.swap_remove(index))
+        } else if let Some(vertices) = self.list("vertices") {
+            graph.induced_subgraph(
+                &vertices
+                    .iter()
+                    .filter_map(|vertex| interner.get_id(vertex))
+                    .collect(),
+            )
+        } else {
+            return Err(String::from("MISSING --ego, --partition OR --vertices"));
+        };
+
+        File::write(
+            &interner.resolve_graph(&subgraph),
+            output,
+            self.optional("to", GraphFormat::EdgeList)?,
+        )?;
+        println!(
+            "Extracted {} vertices and {} edges into {}",
+            subgraph.vertices.len(),
+            subgraph.get_total_of_edges() / 2,
+            output
+        );
+
+        Ok(())
+    }
+
+    fn quotient(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let communities = interner.get_community_ids(&File::read_partition(
+            self.required("partition")?,
+            self.optional("partition-format", PartitionFormat::Communities)?,
+        )?);
+        let quotient = graph.get_quotient_graph(&communities);
+
+        println!("Communities: {}", quotient.len());
+        println!("Linked pairs: {}", quotient.weights.len());
+        println!(
+            "Internal edges: {} of {}",
+            quotient.internal.iter().sum::<usize>(),
+            quotient.get_total_of_edges()
+        );
+        println!("Modularity: {}", quotient.get_modularity(1.));
+
+        if let Some(path) = self.options.get("output") {
+            fs::write(path, quotient.to_edge_list()).map_err(|error| error.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<(), String> {
+        let (_, graph) = self.read_interned_graph()?;
+        let statistics = GraphStatistics::compute(
+            &graph,
+            self.optional_value("samples")?,
+            self.optional("seed", 0)?,
+        );
+
+        if let Some(path) = self.options.get("json") {
+            fs::write(path, statistics.to_json()).map_err(|error| error.to_string())?;
+        }
+        print!("{}", statistics.to_text());
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<(), String> {
+        let partition = File::read_partition(
+            self.required("partition")?,
+            self.optional("partition-format", PartitionFormat::Communities)?,
+        )?;
+        let truth = File::read_partition(
+            self.required("truth")?,
+            self.optional("truth-format", PartitionFormat::Communities)?,
+        )?;
+        let evaluation = Evaluation::compare(&partition, &truth);
+
+        println!("Communities: {} (truth: {})", partition.len(), truth.len());
+        println!("Common vertices: {}", evaluation.common_vertices);
+        println!("NMI: {}", evaluation.nmi);
+        println!("ARI: {}", evaluation.ari);
+
+        if self.options.contains_key("input") {
+            let (interner, graph) = self.read_interned_graph()?;
+            println!(
+                "Modularity: {}",
+                graph.get_modularity(interner.get_community_ids(&partition))
+            );
+        }
+
+        Ok(())
+    }
+
+    fn convert(&self) -> Result<(), String> {
+        let (interner, graph) = self.read_interned_graph()?;
+        let graph = interner.resolve_graph(&graph);
+        let output = self.required("output")?;
+
+        File::write(&graph, output, self.optional("to", GraphFormat::EdgeList)?)?;
+        println!(
+            "Converted {} vertices and {} edges into {}",
+            graph.vertices.len(),
+            graph.get_total_of_edges() / 2,
+            output
+        );
+
+        Ok(())
+    }
+
+    fn generate(&self) -> Result<(), String> {
+        let seed = self.optional("seed", 0)?;
+        let planted = match self.required("model")?.as_str() {
+            "connected_caveman" => Generators::connected_caveman(
+                self.optional("cliques", 10)?,
+                self.optional("size", 10)?,
+            ),
+            "relaxed_caveman" => Generators::relaxed_caveman(
+                self.optional("cliques", 10)?,
+                self.optional("size", 10)?,
+                self.optional("probability", 0.1)?,
+                seed,
+            ),
+            "planted_partition" => Generators::planted_partition(
+                self.optional("groups", 4)?,
+                self.optional("size", 32)?,
+                self.optional("p-in", 0.5)?,
+                self.optional("p-out", 0.01)?,
+                seed,
+            ),
+            "lfr" => Generators::lfr(&self.lfr_parameters()?, seed),
+            "gnp" => PlantedGraph {
+                graph: Generators::erdos_renyi_gnp(
+                    self.optional("n", 1000)?,
+                    self.optional("p", 0.01)?,
+                    seed,
+                ),
+                ..Default::default()
+            },
+            "gnm" => PlantedGraph {
+                graph: Generators::erdos_renyi_gnm(
+                    self.optional("n", 1000)?,
+                    self.optional("m", 5000)?,
+                    seed,
+                ),
+                ..Default::default()
+            },
+            "barabasi_albert" => PlantedGraph {
+                graph: Generators::barabasi_albert(
+                    self.optional("n", 1000)?,
+                    self.optional("m", 3)?,
+                    seed,
+                ),
+                ..Default::default()
+            },
+            "watts_strogatz" => PlantedGraph {
+                graph: Generators::watts_strogatz(
+                    self.optional("n", 1000)?,
+                    self.optional("k", 6)?,
+                    self.optional("p", 0.1)?,
+                    seed,
+                ),
+                ..Default::default()
+            },
+            "configuration_model" => {
+                let (interner, graph) = self.read_interned_graph()?;
+                let graph = interner.resolve_graph(&Generators::configuration_model(&graph, seed));
+                let output = self.required("output")?;
+                File::write(&graph, output, self.optional("to", GraphFormat::EdgeList)?)?;
+                println!(
+                    "Generated {} vertices and {} edges into {}",
+                    graph.vertices.len(),
+                    graph.get_total_of_edges() / 2,
+                    output
+                );
+                return Ok(());
+            }
+            model => return Err(format!("UNKNOWN MODEL: {}", model)),
+        };
+
+        let output = self.required("output")?;
+        File::write(
+            &planted.graph,
+            output,
+            self.optional("to", GraphFormat::EdgeList)?,
+        )?;
+        if let Some(truth) = self.options.get("truth") {
+            File::write_partition(&planted.ground_truth, truth)?;
+        }
+
+        println!(
+            "Generated {} vertices, {} edges and {} planted communities into {}",
+            planted.graph.vertices.len(),
+            planted.graph.get_total_of_edges() / 2,
+            planted.ground_truth.len(),
+            output
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    fmt::{Debug, Display},
+    fs,
+    hash::Hash,
+    io::{BufWriter, Write},
+    str::FromStr,
+};
+
+use memmap2::Mmap;
+
+use super::{File, GraphFormat};
+use crate::graph::{interner::Interner, undirected::UndirectedGraph};
+
+/*
+ *  Formato binário do cache (inteiros little-endian):
+ *
+ *      magic "PRJ2GRPH" | versão u32 | reservado u32
+ *      n u64 | bytes dos rótulos u64 | entradas de adjacência u64
+ *      deslocamentos dos rótulos (n + 1) × u64 | rótulos UTF-8 concatenados
+ *      deslocamentos CSR (n + 1) × u64 | vizinhos (entradas) × u32
+ *      checksum FNV-1a u64 de tudo o que vem antes
+ *
+ *  Cada vértice aparece uma vez na tabela de rótulos (inclusive os isolados), na ordem dos
+ *  identificadores de um `Interner`, e as listas de adjacência guardam apenas os identificadores.
+ */
+const MAGIC: &[u8; 8] = b"PRJ2GRPH";
+const VERSION: u32 = 1;
+const HEADER: usize = 8 + 4 + 4 + 3 * 8;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/*
+ *  Escritor que calcula o checksum dos bytes à medida que eles passam. Guarda o primeiro erro
+ *  de escrita, devolvido por `finish`, para que cada `put` não precise ser verificado.
+ */
+struct ChecksumWriter<W: Write> {
+    inner: W,
+    checksum: u64,
+    error: Option<std::io::Error>,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.checksum = fnv1a(self.checksum, bytes);
+        if self.error.is_none()
+            && let Err(error) = self.inner.write_all(bytes)
+        {
+            self.error = Some(error);
+        }
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => self.inner.flush(),
+        }
+    }
+}
+
+// Leitura sequencial das seções do arquivo mapeado
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or("CACHE IS TRUNCATED")?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<usize, String> {
+        let value = u64::from_le_bytes(self.take(8)?.try_into().unwrap());
+        usize::try_from(value).map_err(|_| String::from("CACHE VALUE DOES NOT FIT IN USIZE"))
+    }
+
+    fn u64s(&mut self, count: usize) -> Result<Vec<usize>, String> {
+        let bytes = self.take(count.checked_mul(8).ok_or("CACHE IS CORRUPTED")?)?;
+        Ok(bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()) as usize)
+            .collect())
+    }
+}
+
+impl File {
+    pub fn write_cache<T>(graph: &UndirectedGraph<T>, path: impl Into<String>) -> Result<(), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let (interner, graph) = Interner::from_graph(graph);
+        Self::write_cache_interned(&interner, &graph, path)
+    }
+
+    // Grava os identificadores de `graph` como estão; `interner` dá o rótulo de cada um
+    pub fn write_cache_interned<T>(
+        interner: &Interner<T>,
+        graph: &UndirectedGraph<usize>,
+        path: impl Into<String>,
+    ) -> Result<(), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        if interner.len() > u32::MAX as usize {
+            return Err(String::from("GRAPH IS TOO LARGE FOR THE CACHE FORMAT"));
+        }
+        let labels: Vec<String> = interner
+            .get_labels()
+            .iter()
+            .map(|vertex| vertex.to_string())
+            .collect();
+
+        let path = path.into();
+        let file = fs::File::create(&path)
+            .map_err(|error| format!("CANNOT CREATE {}: {}", path, error))?;
+        let mut writer = ChecksumWriter {
+            inner: BufWriter::new(file),
+            checksum: FNV_OFFSET,
+            error: None,
+        };
+
+        // Só os identificadores que serão gravados entram na contagem de entradas
+        let neighbourhoods: Vec<Vec<u32>> = (0..interner.len())
+            .map(|id| {
+                graph
+                    .get_neighbourhood(&id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|neighbour| **neighbour < interner.len())
+                    .map(|neighbour| *neighbour as u32)
+                    .collect()
+            })
+            .collect();
+        let entries: usize = neighbourhoods.iter().map(Vec::len).sum();
+
+        writer.put(MAGIC);
+        writer.put(&VERSION.to_le_bytes());
+        writer.put(&0u32.to_le_bytes());
+        writer.put(&(interner.len() as u64).to_le_bytes());
+        writer.put(&(labels.iter().map(String::len).sum::<usize>() as u64).to_le_bytes());
+        writer.put(&(entries as u64).to_le_bytes());
+
+        let mut offset = 0u64;
+        writer.put(&offset.to_le_bytes());
+        for label in &labels {
+            offset += label.len() as u64;
+            writer.put(&offset.to_le_bytes());
+        }
+        for label in &labels {
+            writer.put(label.as_bytes());
+        }
+
+        let mut offset = 0u64;
+        writer.put(&offset.to_le_bytes());
+        for neighbourhood in &neighbourhoods {
+            offset += neighbourhood.len() as u64;
+            writer.put(&offset.to_le_bytes());
+        }
+        for neighbourhood in &neighbourhoods {
+            for id in neighbourhood {
+                writer.put(&id.to_le_bytes());
+            }
+        }
+
+        let checksum = writer.checksum;
+        writer.put(&checksum.to_le_bytes());
+        writer
+            .finish()
+            .map_err(|error| format!("CANNOT WRITE {}: {}", path, error))
+    }
+
+    /*
+     *  Lê o cache por mapeamento de memória. Retorna erro (em vez de entrar em pânico) quando
+     *  o arquivo não é um cache válido desta versão, para que o chamador possa refazê-lo.
+     */
+    pub fn read_cache<T>(path: impl Into<String>) -> Result<UndirectedGraph<T>, String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let (interner, graph) = Self::read_cache_interned::<T>(path)?;
+        Ok(interner.resolve_graph(&graph))
+    }
+
+    // Como `read_cache`, mas mantém os vértices como os identificadores gravados no cache
+    pub fn read_cache_interned<T>(
+        path: impl Into<String>,
+    ) -> Result<(Interner<T>, UndirectedGraph<usize>), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let file = fs::File::open(path.into()).map_err(|error| error.to_string())?;
+        // O arquivo só é lido; alterá-lo durante a leitura é responsabilidade de quem o faz
+        let map = unsafe { Mmap::map(&file) }.map_err(|error| error.to_string())?;
+        let bytes: &[u8] = &map;
+
+        if bytes.len() < HEADER + 8 || &bytes[..8] != MAGIC {
+            return Err(String::from("NOT A GRAPH CACHE"));
+        }
+        let (body, stored) = bytes.split_at(bytes.len() - 8);
+        if fnv1a(FNV_OFFSET, body) != u64::from_le_bytes(stored.try_into().unwrap()) {
+            return Err(String::from("CACHE CHECKSUM MISMATCH"));
+        }
+
+        let mut cursor = Cursor {
+            bytes: body,
+            position: 8,
+        };
+        let version = cursor.u32()?;
+        if version != VERSION {
+            return Err(format!("UNSUPPORTED CACHE VERSION {}", version));
+        }
+        cursor.u32()?;
+        let n = cursor.u64()?;
+        let label_bytes = cursor.u64()?;
+        let entries = cursor.u64()?;
+
+        let label_offsets = cursor.u64s(n + 1)?;
+        let labels = cursor.take(label_bytes)?;
+        let interner: Interner<T> = label_offsets
+            .windows(2)
+            .map(|window| {
+                let label = labels
+                    .get(window[0]..window[1])
+                    .and_then(|label| std::str::from_utf8(label).ok())
+                    .ok_or("CACHE HAS AN INVALID LABEL")?;
+                label
+                    .parse::<T>()
+                    .map_err(|_| format!("CACHE LABEL {} DOES NOT PARSE", label))
+            })
+            .collect::<Result<Interner<T>, String>>()?;
+        if interner.len() != n {
+            return Err(String::from("CACHE HAS REPEATED LABELS"));
+        }
+
+        let offsets = cursor.u64s(n + 1)?;
+        let neighbours = cursor.take(entries.checked_mul(4).ok_or("CACHE IS CORRUPTED")?)?;
+        if offsets.last() != Some(&entries) || offsets.windows(2).any(|w| w[0] > w[1]) {
+            return Err(String::from("CACHE IS CORRUPTED"));
+        }
+
+        let mut graph = UndirectedGraph::new();
+        graph.vertices.reserve(n);
+        graph.adjacency.reserve(n);
+        for id in 0..n {
+            graph.push_vertex(id);
+            if offsets[id] == offsets[id + 1] {
+                continue;
+            }
+
+            let neighbourhood: Vec<usize> = neighbours[4 * offsets[id]..4 * offsets[id + 1]]
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as usize)
+                .collect();
+            if neighbourhood.iter().any(|neighbour| *neighbour >= n) {
+                return Err(String::from("CACHE IS CORRUPTED"));
+            }
+            graph.adjacency.insert(id, neighbourhood);
+        }
+
+        Ok((interner, graph))
+    }
+
+    /*
+     *  Carrega o grafo de `<path>.<formato>.bin` quando o cache existe, é válido e é mais novo
+     *  que o texto; do contrário lê o texto e grava o cache para as próximas execuções. O
+     *  formato entra no nome porque o mesmo arquivo lido em outro formato é outro grafo.
+     */
+    pub fn read_cached<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+    ) -> Result<UndirectedGraph<T>, String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let (interner, graph) = Self::read_cached_interned::<T>(path, format)?;
+        Ok(interner.resolve_graph(&graph))
+    }
+
+    // Como `read_cached`, sem trocar os identificadores de volta pelos rótulos
+    pub fn read_cached_interned<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+    ) -> Result<(Interner<T>, UndirectedGraph<usize>), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let path = path.into();
+        let cache = format!("{}.{}.bin", path, format);
+
+        let modified = |path: &str| fs::metadata(path).and_then(|metadata| metadata.modified());
+        let fresh = match (modified(&path), modified(&cache)) {
+            (Ok(source), Ok(cached)) => cached >= source,
+            _ => false,
+        };
+        if fresh && let Ok(interned) = Self::read_cache_interned(&cache) {
+            return Ok(interned);
+        }
+
+        let (interner, graph, _) =
+            Self::read_streaming_interned::<T>(&path, format, Self::DEFAULT_CHUNK_SIZE)?;
+        Self::write_cache_interned(&interner, &graph, &cache)?;
+        Ok((interner, graph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temporary(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("projeto_2_rs_{}_{}", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn sample() -> UndirectedGraph<String> {
+        let mut graph = UndirectedGraph::from(
+            [["a", "b"], ["b", "c"], ["c", "a"], ["c", "d"]]
+                .map(|[u, v]| [u.to_string(), v.to_string()])
+                .to_vec(),
+        );
+        graph.push_vertex(String::from("isolated"));
+        graph
+    }
+
+    fn assert_same_graph(x: &UndirectedGraph<String>, y: &UndirectedGraph<String>) {
+        assert_eq!(x.vertices, y.vertices);
+        for vertex in &x.vertices {
+            let neighbours = |graph: &UndirectedGraph<String>| {
+                let mut neighbours = graph.get_neighbourhood(vertex).cloned().unwrap_or_default();
+                neighbours.sort();
+                neighbours
+            };
+            assert_eq!(neighbours(x), neighbours(y));
+        }
+    }
+
+    #[test]
+    fn round_trip_keeps_vertices_and_edges() {
+        let path = temporary("round_trip.bin");
+        let graph = sample();
+
+        File::write_cache(&graph, &path).unwrap();
+        let read = File::read_cache::<String>(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_same_graph(&graph, &read);
+    }
+
+    #[test]
+    fn corrupted_cache_is_rejected() {
+        let path = temporary("corrupted.bin");
+        File::write_cache(&sample(), &path).unwrap();
+
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[HEADER + 3] ^= 0xff;
+        fs::write(&path, &bytes).unwrap();
+        assert!(File::read_cache::<String>(&path).is_err());
+
+        fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+        assert!(File::read_cache::<String>(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,27 +1,357 @@
-use std::{fs, str::FromStr};
+pub mod cache;
+pub mod stream;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    io::{BufWriter, Write},
+    str::FromStr,
+};
+
+use crate::graph::{
+    undirected::{Community, UndirectedGraph},
+    utils::Utils,
+};
 
 pub struct File;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    EdgeList,
+    Csv,
+    AdjacencyList,
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "edgelist" | "txt" => Ok(Self::EdgeList),
+            "csv" => Ok(Self::Csv),
+            "adjlist" => Ok(Self::AdjacencyList),
+            _ => Err(format!("UNKNOWN GRAPH FORMAT: {}", value)),
+        }
+    }
+}
+
+impl Display for GraphFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EdgeList => write!(f, "edgelist"),
+            Self::Csv => write!(f, "csv"),
+            Self::AdjacencyList => write!(f, "adjlist"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionFormat {
+    // Uma comunidade por linha, como em `Utils::persist_communities`
+    Communities,
+    // Um par `vértice rótulo` por linha
+    Membership,
+}
+
+impl FromStr for PartitionFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "communities" => Ok(Self::Communities),
+            "membership" => Ok(Self::Membership),
+            _ => Err(format!("UNKNOWN PARTITION FORMAT: {}", value)),
+        }
+    }
+}
+
 impl File {
-    pub fn read<T>(path: impl Into<String>) -> Vec<[T; 2]>
+    // Mensagens de erro de leitura e escrita levam o caminho do arquivo
+    fn read_to_string(path: impl Into<String>) -> Result<String, String> {
+        let path = path.into();
+        fs::read_to_string(&path).map_err(|error| format!("CANNOT READ {}: {}", path, error))
+    }
+
+    fn create(path: impl Into<String>) -> Result<(String, BufWriter<fs::File>), String> {
+        let path = path.into();
+        let file = fs::File::create(&path)
+            .map_err(|error| format!("CANNOT CREATE {}: {}", path, error))?;
+        Ok((path, BufWriter::new(file)))
+    }
+
+    fn write_error(path: &str) -> impl Fn(std::io::Error) -> String + '_ {
+        move |error| format!("CANNOT WRITE {}: {}", path, error)
+    }
+
+    pub fn read<T>(path: impl Into<String>) -> Result<Vec<[T; 2]>, String>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: std::fmt::Debug,
+    {
+        Self::read_with_format(path, GraphFormat::EdgeList)
+    }
+
+    pub fn read_with_format<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+    ) -> Result<Vec<[T; 2]>, String>
     where
         T: FromStr,
         <T as FromStr>::Err: std::fmt::Debug,
     {
-        let file_as_string =
-            fs::read_to_string(path.into()).expect("FILE: INCORRECT PATH OR FILE INEXISTENT");
+        let file_as_string = Self::read_to_string(path)?;
+
+        Ok(match format {
+            GraphFormat::EdgeList => {
+                Self::parse_pairs(&file_as_string, |line| line.split_whitespace().collect())
+            }
+            GraphFormat::Csv => Self::parse_pairs(&file_as_string, |line| {
+                line.split(',').map(str::trim).collect()
+            }),
+            GraphFormat::AdjacencyList => file_as_string
+                .lines()
+                .flat_map(|line| {
+                    let mut fields = line.split_whitespace();
+                    let vertex = fields.next().map(|s| s.to_string());
+                    fields
+                        .filter_map(move |neighbour| {
+                            let vertex = vertex.as_ref()?.parse::<T>().ok()?;
+                            Some([vertex, neighbour.parse::<T>().ok()?])
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        })
+    }
+
+    /*
+     *  Lê uma lista de arestas com uma terceira coluna numérica de tempo (`u v t`). Linhas sem
+     *  tempo válido são ignoradas.
+     */
+    pub fn read_timestamped<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+    ) -> Result<Vec<([T; 2], f64)>, String>
+    where
+        T: FromStr,
+    {
+        let separator = match format {
+            GraphFormat::EdgeList => None,
+            GraphFormat::Csv => Some(','),
+            GraphFormat::AdjacencyList => {
+                return Err(String::from("ADJACENCY LISTS HAVE NO TIMESTAMPS"));
+            }
+        };
+        let file_as_string = Self::read_to_string(path)?;
 
-        let data: Vec<[T; 2]> = file_as_string
+        Ok(file_as_string
             .lines()
             .filter_map(|line| {
-                let mut nums = line.split_whitespace().filter_map(|s| s.parse::<T>().ok());
+                let fields: Vec<&str> = match separator {
+                    Some(separator) => line.split(separator).map(str::trim).collect(),
+                    None => line.split_whitespace().collect(),
+                };
+                match fields[..] {
+                    [from, to, time, ..] => Some((
+                        [from.parse::<T>().ok()?, to.parse::<T>().ok()?],
+                        time.parse::<f64>().ok().filter(|time| time.is_finite())?,
+                    )),
+                    _ => None,
+                }
+            })
+            .collect())
+    }
+
+    fn parse_pairs<T>(file_as_string: &str, split: impl Fn(&str) -> Vec<&str>) -> Vec<[T; 2]>
+    where
+        T: FromStr,
+    {
+        file_as_string
+            .lines()
+            .filter_map(|line| {
+                let mut nums = split(line).into_iter().filter_map(|s| s.parse::<T>().ok());
                 match (nums.next(), nums.next()) {
                     (Some(a), Some(b)) => Some([a, b]),
                     _ => None,
                 }
             })
-            .collect();
+            .collect()
+    }
+
+    pub fn write<T>(
+        graph: &UndirectedGraph<T>,
+        path: impl Into<String>,
+        format: GraphFormat,
+    ) -> Result<(), String>
+    where
+        T: Send + Sync + Eq + std::hash::Hash + Clone + std::fmt::Debug + Display + Default,
+    {
+        let (path, mut writer) = Self::create(path)?;
+        let error = Self::write_error(&path);
+
+        match format {
+            GraphFormat::EdgeList | GraphFormat::Csv => {
+                let separator = if format == GraphFormat::Csv { "," } else { " " };
+                let mut written: HashSet<(&T, &T)> = HashSet::new();
+
+                for (vertex, neighbourhood) in &graph.adjacency {
+                    for neighbour in neighbourhood {
+                        if written.contains(&(neighbour, vertex)) {
+                            continue;
+                        }
+                        written.insert((vertex, neighbour));
+                        writeln!(writer, "{}{}{}", vertex, separator, neighbour).map_err(&error)?;
+                    }
+                }
+            }
+            GraphFormat::AdjacencyList => {
+                // Vértices isolados ficam numa linha sozinhos
+                for vertex in &graph.vertices {
+                    write!(writer, "{}", vertex).map_err(&error)?;
+                    for neighbour in graph.get_neighbourhood(vertex).into_iter().flatten() {
+                        write!(writer, " {}", neighbour).map_err(&error)?;
+                    }
+                    writeln!(writer).map_err(&error)?;
+                }
+            }
+        }
+        writer.flush().map_err(&error)
+    }
+
+    pub fn read_partition(
+        path: impl Into<String>,
+        format: PartitionFormat,
+    ) -> Result<Vec<Community<String>>, String> {
+        let file_as_string = Self::read_to_string(path)?;
+
+        Ok(match format {
+            PartitionFormat::Communities => file_as_string
+                .lines()
+                .map(|line| line.split_whitespace().map(|s| s.to_string()).collect())
+                .filter(|community: &Community<String>| !community.is_empty())
+                .collect(),
+            PartitionFormat::Membership => {
+                let mut labels: HashMap<String, usize> = HashMap::new();
+                let mut communities: Vec<Community<String>> = Vec::new();
+
+                for line in file_as_string.lines() {
+                    let fields: Vec<&str> = line
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                    if fields.len() < 2 {
+                        continue;
+                    }
+
+                    let position = *labels.entry(fields[1].to_string()).or_insert_with(|| {
+                        communities.push(Community::new());
+                        communities.len() - 1
+                    });
+                    communities[position].insert(fields[0].to_string());
+                }
+
+                communities
+            }
+        })
+    }
+
+    pub fn write_partition<T: Display>(
+        communities: &[Community<T>],
+        path: impl Into<String>,
+    ) -> Result<(), String> {
+        let (path, mut writer) = Self::create(path)?;
+        let error = Self::write_error(&path);
+
+        for community in communities {
+            let line: Vec<String> = Utils::stable_order(community.iter().collect(), None)
+                .iter()
+                .map(|vertex| vertex.to_string())
+                .collect();
+            writeln!(writer, "{}", line.join(" ")).map_err(&error)?;
+        }
+        writer.flush().map_err(&error)
+    }
+
+    // Um par `vértice comunidade` por linha, lido de volta por `PartitionFormat::Membership`
+    pub fn write_membership<T: Display>(
+        communities: &[Community<T>],
+        path: impl Into<String>,
+    ) -> Result<(), String> {
+        let (path, mut writer) = Self::create(path)?;
+        let error = Self::write_error(&path);
+
+        for (index, community) in communities.iter().enumerate() {
+            for vertex in Utils::stable_order(community.iter().collect(), None) {
+                writeln!(writer, "{} {}", vertex, index).map_err(&error)?;
+            }
+        }
+        writer.flush().map_err(&error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temporary(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("projeto_2_rs_{}_{}", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn missing_files_are_errors() {
+        let path = temporary("missing.txt");
+
+        assert!(File::read_with_format::<usize>(&path, GraphFormat::EdgeList).is_err());
+        assert!(File::read_timestamped::<usize>(&path, GraphFormat::EdgeList).is_err());
+        assert!(File::read_partition(&path, PartitionFormat::Communities).is_err());
+        assert!(File::write_partition::<usize>(&[], temporary("missing/x.txt")).is_err());
+    }
+
+    #[test]
+    fn written_memberships_read_back_as_the_same_partition() {
+        let communities: Vec<Community<String>> = vec![
+            ["a", "b"].iter().map(|s| s.to_string()).collect(),
+            ["c"].iter().map(|s| s.to_string()).collect(),
+        ];
+        let path = temporary("membership.txt");
+        File::write_membership(&communities, &path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let read = File::read_partition(&path, PartitionFormat::Membership).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents, "a 0\nb 0\nc 1\n");
+        assert_eq!(read, communities);
+    }
+
+    #[test]
+    fn written_graphs_read_back_in_every_format() {
+        let graph = UndirectedGraph::from(vec![[1, 2], [2, 3], [3, 1], [3, 4]]);
+
+        for format in [
+            GraphFormat::EdgeList,
+            GraphFormat::Csv,
+            GraphFormat::AdjacencyList,
+        ] {
+            let path = temporary(&format!("written.{}", format));
+            File::write(&graph, &path, format).unwrap();
+            let read =
+                UndirectedGraph::from(File::read_with_format::<usize>(&path, format).unwrap());
+            fs::remove_file(&path).unwrap();
 
-        data
+            assert_eq!(read.vertices, graph.vertices, "{}", format);
+            for vertex in &graph.vertices {
+                let neighbours = |graph: &UndirectedGraph<usize>| {
+                    let mut neighbours =
+                        graph.get_neighbourhood(vertex).cloned().unwrap_or_default();
+                    neighbours.sort();
+                    neighbours
+                };
+                assert_eq!(neighbours(&read), neighbours(&graph), "{}", format);
+            }
+        }
     }
 }
@@ -0,0 +1,342 @@
+use std::{
+    fmt::{Debug, Display},
+    fs,
+    hash::Hash,
+    io::{BufReader, Read},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use super::{File, GraphFormat};
+use crate::graph::{
+    interner::Interner,
+    undirected::{
+        UndirectedGraph,
+        builder::{GraphBuilder, InternedBuilder},
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamReport {
+    pub bytes: usize,
+    pub lines: usize,
+    // Pares lidos; numa lista de adjacência cada aresta costuma aparecer duas vezes
+    pub edges: usize,
+    // Linhas em branco ou iniciadas por `#` ou `%`
+    pub comments: usize,
+    // Linhas com conteúdo que não puderam ser lidas como aresta
+    pub skipped: usize,
+    pub chunks: usize,
+    pub elapsed: Duration,
+}
+
+impl StreamReport {
+    pub fn get_megabytes_per_second(&self) -> f64 {
+        self.bytes as f64 / 1e6 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    pub fn get_edges_per_second(&self) -> f64 {
+        self.edges as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+impl Display for StreamReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Read {} bytes in {} chunks: {} lines, {} edges, {} skipped, {} blank or comments \
+             in {:?} ({:.1} MB/s, {:.0} edges/s)",
+            self.bytes,
+            self.chunks,
+            self.lines,
+            self.edges,
+            self.skipped,
+            self.comments,
+            self.elapsed,
+            self.get_megabytes_per_second(),
+            self.get_edges_per_second()
+        )
+    }
+}
+
+// Resultado da leitura de um pedaço, combinado entre as threads do rayon
+struct Parsed<T> {
+    edges: Vec<[T; 2]>,
+    vertices: Vec<T>,
+    lines: usize,
+    comments: usize,
+    skipped: usize,
+}
+
+impl<T> Default for Parsed<T> {
+    fn default() -> Self {
+        Self {
+            edges: Vec::new(),
+            vertices: Vec::new(),
+            lines: 0,
+            comments: 0,
+            skipped: 0,
+        }
+    }
+}
+
+impl<T> Parsed<T> {
+    fn merge(mut self, mut other: Self) -> Self {
+        self.edges.append(&mut other.edges);
+        self.vertices.append(&mut other.vertices);
+        self.lines += other.lines;
+        self.comments += other.comments;
+        self.skipped += other.skipped;
+        self
+    }
+}
+
+impl File {
+    pub const DEFAULT_CHUNK_SIZE: usize = 8 << 20;
+
+    /*
+     *  Lê o arquivo em pedaços de cerca de `chunk_size` bytes, cortados no último fim de linha.
+     *  As linhas de cada pedaço são lidas em paralelo enquanto o próximo pedaço é carregado, e
+     *  as arestas vão direto para um `GraphBuilder`: além do grafo, só dois pedaços ficam em
+     *  memória ao mesmo tempo.
+     */
+    pub fn read_streaming<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+        chunk_size: usize,
+    ) -> Result<(UndirectedGraph<T>, StreamReport), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let start = Instant::now();
+        let mut builder = GraphBuilder::new();
+        let mut report = Self::stream_chunks::<T>(path, format, chunk_size, |parsed| {
+            for vertex in parsed.vertices {
+                builder.push_vertex(vertex);
+            }
+            builder.extend(parsed.edges);
+        })?;
+
+        let graph = builder.build();
+        report.elapsed = start.elapsed();
+        Ok((graph, report))
+    }
+
+    // Como `read_streaming`, mas interna os rótulos enquanto lê, sem montar o grafo com rótulos
+    pub fn read_streaming_interned<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+        chunk_size: usize,
+    ) -> Result<(Interner<T>, UndirectedGraph<usize>, StreamReport), String>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default + FromStr,
+    {
+        let start = Instant::now();
+        let mut builder = InternedBuilder::new();
+        let mut report = Self::stream_chunks::<T>(path, format, chunk_size, |parsed| {
+            for vertex in parsed.vertices {
+                builder.push_vertex(vertex);
+            }
+            builder.extend(parsed.edges);
+        })?;
+
+        let (interner, graph) = builder.build();
+        report.elapsed = start.elapsed();
+        Ok((interner, graph, report))
+    }
+
+    // Entrega a `consume` o resultado de cada pedaço, na ordem do arquivo
+    fn stream_chunks<T>(
+        path: impl Into<String>,
+        format: GraphFormat,
+        chunk_size: usize,
+        mut consume: impl FnMut(Parsed<T>),
+    ) -> Result<StreamReport, String>
+    where
+        T: Send + Clone + FromStr,
+    {
+        let path = path.into();
+        let error = |error: std::io::Error| format!("CANNOT READ {}: {}", path, error);
+        let file = fs::File::open(&path).map_err(error)?;
+        let mut reader = BufReader::new(file);
+        let mut carry: Vec<u8> = Vec::new();
+        let chunk_size = chunk_size.max(1);
+
+        let mut report = StreamReport::default();
+        let mut chunk = Self::next_chunk(&mut reader, &mut carry, chunk_size).map_err(error)?;
+
+        while !chunk.is_empty() {
+            let (parsed, next) = rayon::join(
+                || Self::parse_chunk::<T>(&chunk, format),
+                || Self::next_chunk(&mut reader, &mut carry, chunk_size),
+            );
+
+            report.bytes += chunk.len();
+            report.chunks += 1;
+            report.lines += parsed.lines;
+            report.comments += parsed.comments;
+            report.skipped += parsed.skipped;
+            report.edges += parsed.edges.len();
+            consume(parsed);
+
+            chunk = next.map_err(error)?;
+        }
+
+        Ok(report)
+    }
+
+    // Próximo pedaço terminado em fim de linha (ou o resto do arquivo); vazio ao fim
+    fn next_chunk(
+        reader: &mut impl Read,
+        carry: &mut Vec<u8>,
+        chunk_size: usize,
+    ) -> std::io::Result<Vec<u8>> {
+        let mut buffer = std::mem::take(carry);
+        loop {
+            let filled = buffer.len();
+            buffer.resize(filled + chunk_size, 0);
+            let read = reader.read(&mut buffer[filled..])?;
+            buffer.truncate(filled + read);
+
+            if read == 0 {
+                return Ok(buffer);
+            }
+            // Linhas maiores que o pedaço fazem o buffer crescer até o próximo fim de linha
+            if let Some(end) = buffer[filled..].iter().rposition(|byte| *byte == b'\n') {
+                *carry = buffer.split_off(filled + end + 1);
+                return Ok(buffer);
+            }
+        }
+    }
+
+    fn parse_chunk<T>(chunk: &[u8], format: GraphFormat) -> Parsed<T>
+    where
+        T: Send + Clone + FromStr,
+    {
+        let text = String::from_utf8_lossy(chunk);
+
+        text.par_lines()
+            .fold(Parsed::default, |mut parsed, line| {
+                parsed.lines += 1;
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+                    parsed.comments += 1;
+                    return parsed;
+                }
+
+                let read = match format {
+                    GraphFormat::EdgeList => Self::parse_edge(line.split_whitespace(), &mut parsed),
+                    GraphFormat::Csv => {
+                        Self::parse_edge(line.split(',').map(str::trim), &mut parsed)
+                    }
+                    GraphFormat::AdjacencyList => {
+                        let mut fields = line.split_whitespace();
+                        match fields.next().and_then(|field| field.parse::<T>().ok()) {
+                            Some(vertex) => {
+                                for neighbour in fields.filter_map(|field| field.parse::<T>().ok())
+                                {
+                                    parsed.edges.push([vertex.clone(), neighbour]);
+                                }
+                                parsed.vertices.push(vertex);
+                                true
+                            }
+                            None => false,
+                        }
+                    }
+                };
+                if !read {
+                    parsed.skipped += 1;
+                }
+                parsed
+            })
+            .reduce(Parsed::default, Parsed::merge)
+    }
+
+    fn parse_edge<'a, T>(mut fields: impl Iterator<Item = &'a str>, parsed: &mut Parsed<T>) -> bool
+    where
+        T: FromStr,
+    {
+        match (
+            fields.next().and_then(|field| field.parse::<T>().ok()),
+            fields.next().and_then(|field| field.parse::<T>().ok()),
+        ) {
+            (Some(from), Some(to)) => {
+                parsed.edges.push([from, to]);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temporary(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir()
+            .join(format!("projeto_2_rs_{}_{}", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn assert_same_as_sequential(name: &str, contents: &str, format: GraphFormat) {
+        let path = temporary(name, contents);
+        let expected =
+            UndirectedGraph::from(File::read_with_format::<usize>(&path, format).unwrap());
+
+        // Pedaços menores que uma linha forçam linhas cortadas entre pedaços
+        for chunk_size in [1, 3, 7, 64, File::DEFAULT_CHUNK_SIZE] {
+            let (graph, report) = File::read_streaming::<usize>(&path, format, chunk_size).unwrap();
+            assert_eq!(
+                graph.vertices, expected.vertices,
+                "chunk_size {}",
+                chunk_size
+            );
+            for vertex in &expected.vertices {
+                assert_eq!(
+                    graph.get_neighbourhood(vertex),
+                    expected.get_neighbourhood(vertex),
+                    "chunk_size {}",
+                    chunk_size
+                );
+            }
+            assert_eq!(report.bytes, contents.len());
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn edge_list_matches_sequential_loader() {
+        let contents = "% comentário\n1 2\n2 3\n\n3 1\n10 200\n1 2\n3000 4\n200 3";
+        assert_same_as_sequential("edges.txt", contents, GraphFormat::EdgeList);
+    }
+
+    #[test]
+    fn csv_matches_sequential_loader() {
+        let contents = "1, 2\n2,3\n3 ,1\n10,200\n200,3\n";
+        assert_same_as_sequential("edges.csv", contents, GraphFormat::Csv);
+    }
+
+    #[test]
+    fn adjacency_list_matches_sequential_loader() {
+        let contents = "1 2 3 10\n2 1 3\n3 1 2 200\n10 1\n200 3\n";
+        assert_same_as_sequential("edges.adjlist", contents, GraphFormat::AdjacencyList);
+    }
+
+    #[test]
+    fn report_counts_comments_and_skipped_lines() {
+        let path = temporary("report.txt", "# cabeçalho\n1 2\n\nnão é aresta\n2 3\n");
+        let (_, report) = File::read_streaming::<usize>(&path, GraphFormat::EdgeList, 4).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(report.lines, 5);
+        assert_eq!(report.edges, 2);
+        assert_eq!(report.comments, 2);
+        assert_eq!(report.skipped, 1);
+    }
+}
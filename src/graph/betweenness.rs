@@ -1,6 +1,7 @@
-use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+use std::{cmp::Ordering, collections::HashMap, fmt::Display, hash::Hash};
 
-use super::edge::Edge;
+use super::{edge::Edge, utils::Utils};
+use crate::utils::random::Random;
 
 #[derive(Debug, Clone, Default)]
 pub struct Betweenness<T: Eq + Hash> {
@@ -40,3 +41,25 @@ where
         self.values.insert(key, value);
     }
 }
+
+impl<T> Betweenness<T>
+where
+    T: Eq + Hash + Clone + Display,
+{
+    // Desempata arestas de mesmo valor de forma reprodutível
+    pub fn get_stable_max(&self, random: &mut Random) -> Option<(&Edge<T>, &f64)> {
+        let (_, max) = self.get_max()?;
+        let mut ties: Vec<(&Edge<T>, &f64)> = self
+            .values
+            .iter()
+            .filter(|(_, value)| *value == max)
+            .collect();
+
+        ties.sort_by_cached_key(|(edge, _)| {
+            let (from, to) = (Utils::stable_key(&edge.from), Utils::stable_key(&edge.to));
+            if from < to { (from, to) } else { (to, from) }
+        });
+
+        random.choose(&ties).copied()
+    }
+}
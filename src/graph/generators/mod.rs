@@ -0,0 +1,83 @@
+pub mod null_models;
+pub mod planted;
+
+use crate::{
+    graph::undirected::{Community, UndirectedGraph},
+    utils::random::Random,
+};
+
+pub struct Generators;
+
+#[derive(Debug, Clone, Default)]
+pub struct PlantedGraph {
+    pub graph: UndirectedGraph<usize>,
+    pub ground_truth: Vec<Community<usize>>,
+}
+
+impl Generators {
+    // Grafo com os vértices `0..n` e nenhuma aresta
+    pub(crate) fn empty(n: usize) -> UndirectedGraph<usize> {
+        let mut graph = UndirectedGraph::new();
+        for vertex in 0..n {
+            graph.push_vertex(vertex);
+        }
+        graph
+    }
+
+    pub(crate) fn contiguous_communities(sizes: &[usize]) -> Vec<Community<usize>> {
+        let mut start = 0;
+        sizes
+            .iter()
+            .map(|size| {
+                let community = (start..start + size).collect();
+                start += size;
+                community
+            })
+            .collect()
+    }
+
+    /*
+     *  Amostra um inteiro em [min, max] com P(k) proporcional a k^(-exponent), usando a
+     *  distribuição acumulada pré-calculada
+     */
+    pub(crate) fn power_law_cdf(min: usize, max: usize, exponent: f64) -> Vec<f64> {
+        let mut cdf: Vec<f64> = Vec::with_capacity(max - min + 1);
+        let mut total = 0.;
+        for k in min..=max {
+            total += (k as f64).powf(-exponent);
+            cdf.push(total);
+        }
+        cdf.iter_mut().for_each(|value| *value /= total);
+        cdf
+    }
+
+    pub(crate) fn sample_cdf(cdf: &[f64], min: usize, random: &mut Random) -> usize {
+        let u = random.next_f64();
+        min + cdf.partition_point(|value| *value < u).min(cdf.len() - 1)
+    }
+
+    /*
+     *  Pareia pontas ("stubs") aleatoriamente, descartando laços, arestas repetidas e pares
+     *  recusados por `accept`. Retorna a quantidade de arestas criadas.
+     */
+    pub(crate) fn match_stubs(
+        graph: &mut UndirectedGraph<usize>,
+        mut stubs: Vec<usize>,
+        random: &mut Random,
+        accept: impl Fn(usize, usize) -> bool,
+    ) -> usize {
+        let mut created = 0;
+        random.shuffle(&mut stubs);
+
+        for pair in stubs.chunks_exact(2) {
+            let (u, v) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if u == v || !accept(u, v) || graph.has_edge(&u, &v) {
+                continue;
+            }
+            graph.push_undirected_edge(u, v);
+            created += 1;
+        }
+
+        created
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::Generators;
+use crate::{
+    graph::{edge::Edge, undirected::UndirectedGraph},
+    utils::random::Random,
+};
+
+impl Generators {
+    /*
+     *  G(n, p) pelo método de Batagelj–Brandes: em vez de sortear cada um dos n(n-1)/2 pares,
+     *  salta diretamente para o próximo par escolhido, em O(n + m)
+     */
+    pub fn erdos_renyi_gnp(n: usize, p: f64, seed: u64) -> UndirectedGraph<usize> {
+        let mut random = Random::new(seed);
+        let mut graph = Self::empty(n);
+
+        if p <= 0. || n < 2 {
+            return graph;
+        }
+        if p >= 1. {
+            for u in 0..n {
+                for v in u + 1..n {
+                    graph.push_undirected_edge(u, v);
+                }
+            }
+            return graph;
+        }
+
+        let log_q = (1. - p).ln();
+        let (mut v, mut w): (usize, i64) = (1, -1);
+        while v < n {
+            let r = 1. - random.next_f64();
+            w += 1 + (r.ln() / log_q).floor() as i64;
+            while w >= v as i64 && v < n {
+                w -= v as i64;
+                v += 1;
+            }
+            if v < n {
+                graph.push_undirected_edge(v, w as usize);
+            }
+        }
+
+        graph
+    }
+
+    pub fn erdos_renyi_gnm(n: usize, m: usize, seed: u64) -> UndirectedGraph<usize> {
+        assert!(
+            m <= n * n.saturating_sub(1) / 2,
+            "ERROR: G(n, m) CANNOT HAVE MORE EDGES THAN n(n-1)/2"
+        );
+
+        let mut random = Random::new(seed);
+        let mut graph = Self::empty(n);
+        let mut edges = 0;
+
+        while edges < m {
+            let (u, v) = (random.gen_range(n), random.gen_range(n));
+            if u == v || graph.has_edge(&u, &v) {
+                continue;
+            }
+            graph.push_undirected_edge(u, v);
+            edges += 1;
+        }
+
+        graph
+    }
+
+    /*
+     *  Ligação preferencial: começa com uma estrela de m + 1 vértices e cada novo vértice liga-se
+     *  a m vértices distintos escolhidos com probabilidade proporcional ao grau
+     */
+    pub fn barabasi_albert(n: usize, m: usize, seed: u64) -> UndirectedGraph<usize> {
+        assert!(m >= 1 && m < n, "ERROR: BARABASI-ALBERT NEEDS 1 <= m < n");
+
+        let mut random = Random::new(seed);
+        let mut graph = Self::empty(n);
+        let mut repeated_vertices: Vec<usize> = Vec::new();
+
+        for vertex in 1..=m {
+            graph.push_undirected_edge(0, vertex);
+            repeated_vertices.extend([0, vertex]);
+        }
+
+        for vertex in m + 1..n {
+            let mut targets: Vec<usize> = Vec::with_capacity(m);
+            while targets.len() < m {
+                let target = *random.choose(&repeated_vertices).unwrap();
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+
+            for target in targets {
+                graph.push_undirected_edge(vertex, target);
+                repeated_vertices.extend([vertex, target]);
+            }
+        }
+
+        graph
+    }
+
+    // Anel em que cada vértice liga-se aos k vizinhos mais próximos, com religação `p`
+    pub fn watts_strogatz(n: usize, k: usize, p: f64, seed: u64) -> UndirectedGraph<usize> {
+        assert!(k < n, "ERROR: WATTS-STROGATZ NEEDS k < n");
+
+        let mut random = Random::new(seed);
+        let mut graph = Self::empty(n);
+
+        for offset in 1..=k / 2 {
+            for u in 0..n {
+                graph.push_undirected_edge(u, (u + offset) % n);
+            }
+        }
+
+        for offset in 1..=k / 2 {
+            for u in 0..n {
+                let v = (u + offset) % n;
+                if !random.gen_bool(p) || !graph.has_edge(&u, &v) {
+                    continue;
+                }
+
+                let w = random.gen_range(n);
+                if w == u || graph.has_edge(&u, &w) {
+                    continue;
+                }
+
+                graph.remove_edge(&Edge { from: u, to: v });
+                graph.push_undirected_edge(u, w);
+            }
+        }
+
+        graph
+    }
+
+    /*
+     *  Modelo de configuração com a mesma sequência de graus de `graph`. As pontas que
+     *  formariam laços ou arestas repetidas são reembaralhadas algumas vezes antes de serem
+     *  descartadas, então a sequência de graus é preservada exceto nesses poucos casos.
+     */
+    pub fn configuration_model<T>(graph: &UndirectedGraph<T>, seed: u64) -> UndirectedGraph<T>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        const ATTEMPTS: usize = 10;
+
+        let mut random = Random::new(seed);
+        let vertices = graph.get_ordered_vertices(None);
+        let index: HashMap<&T, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+
+        let mut stubs: Vec<usize> = Vec::new();
+        for vertex in &vertices {
+            let degree = graph.get_neighbourhood(vertex).map_or(0, Vec::len);
+            stubs.extend(std::iter::repeat_n(index[vertex], degree));
+        }
+
+        let mut indexed = Self::empty(vertices.len());
+        for _ in 0..ATTEMPTS {
+            if stubs.len() < 2 {
+                break;
+            }
+
+            random.shuffle(&mut stubs);
+            let mut rejected: Vec<usize> = Vec::new();
+            for pair in stubs.chunks_exact(2) {
+                let (u, v) = (pair[0], pair[1]);
+                if u == v || indexed.has_edge(&u, &v) {
+                    rejected.extend([u, v]);
+                    continue;
+                }
+                indexed.push_undirected_edge(u, v);
+            }
+            stubs = rejected;
+        }
+
+        let mut result = UndirectedGraph::new();
+        for vertex in &vertices {
+            result.push_vertex(vertex.clone());
+        }
+        for (u, neighbourhood) in &indexed.adjacency {
+            for v in neighbourhood {
+                if u < v {
+                    result.push_undirected_edge(vertices[*u].clone(), vertices[*v].clone());
+                }
+            }
+        }
+
+        result
+    }
+
+    /*
+     *  Aleatoriza `graph` por trocas duplas de arestas: (a, b), (c, d) viram (a, d), (c, b) ou
+     *  (a, c), (b, d). Trocas que criariam laços ou arestas repetidas são recusadas, então o grau
+     *  de cada vértice é preservado exatamente.
+     */
+    pub fn double_edge_swap<T>(
+        graph: &UndirectedGraph<T>,
+        swaps_per_edge: usize,
+        seed: u64,
+    ) -> UndirectedGraph<T>
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        let mut random = Random::new(seed);
+        let vertices = graph.get_ordered_vertices(None);
+        let index: HashMap<&T, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for vertex in &vertices {
+            for neighbour in graph.get_neighbourhood(vertex).into_iter().flatten() {
+                let (u, v) = (index[vertex], index[neighbour]);
+                if u < v {
+                    edges.push((u, v));
+                }
+            }
+        }
+        edges.sort_unstable();
+
+        let mut existing: HashSet<(usize, usize)> = edges.iter().copied().collect();
+        if edges.len() >= 2 {
+            for _ in 0..swaps_per_edge * edges.len() {
+                let (i, j) = (random.gen_range(edges.len()), random.gen_range(edges.len()));
+                let ((a, b), (c, d)) = (edges[i], edges[j]);
+                let (first, second) = if random.gen_bool(0.5) {
+                    ((a, d), (c, b))
+                } else {
+                    ((a, c), (b, d))
+                };
+                let first = (first.0.min(first.1), first.0.max(first.1));
+                let second = (second.0.min(second.1), second.0.max(second.1));
+
+                if first.0 == first.1
+                    || second.0 == second.1
+                    || first == second
+                    || existing.contains(&first)
+                    || existing.contains(&second)
+                {
+                    continue;
+                }
+
+                existing.remove(&edges[i]);
+                existing.remove(&edges[j]);
+                existing.insert(first);
+                existing.insert(second);
+                edges[i] = first;
+                edges[j] = second;
+            }
+        }
+
+        let mut result = UndirectedGraph::new();
+        for vertex in &vertices {
+            result.push_vertex(vertex.clone());
+        }
+        for (u, v) in edges {
+            result.push_undirected_edge(vertices[u].clone(), vertices[v].clone());
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrees(graph: &UndirectedGraph<usize>) -> HashMap<usize, usize> {
+        graph
+            .vertices
+            .iter()
+            .map(|vertex| (*vertex, graph.get_neighbourhood(vertex).map_or(0, Vec::len)))
+            .collect()
+    }
+
+    fn is_simple(graph: &UndirectedGraph<usize>) -> bool {
+        graph.adjacency.iter().all(|(vertex, neighbourhood)| {
+            let distinct: HashSet<&usize> = neighbourhood.iter().collect();
+            !distinct.contains(vertex) && distinct.len() == neighbourhood.len()
+        })
+    }
+
+    #[test]
+    fn erdos_renyi_gnm_has_exactly_m_edges() {
+        let graph = Generators::erdos_renyi_gnm(50, 120, 3);
+
+        assert_eq!(graph.vertices.len(), 50);
+        assert_eq!(graph.get_total_of_edges() / 2, 120);
+        assert!(is_simple(&graph));
+    }
+
+    #[test]
+    fn erdos_renyi_gnp_extremes() {
+        assert_eq!(
+            Generators::erdos_renyi_gnp(30, 0., 1).get_total_of_edges(),
+            0
+        );
+        assert_eq!(
+            Generators::erdos_renyi_gnp(30, 1., 1).get_total_of_edges() / 2,
+            30 * 29 / 2
+        );
+        let graph = Generators::erdos_renyi_gnp(200, 0.05, 7);
+        assert!(is_simple(&graph));
+        assert_eq!(
+            graph.adjacency,
+            Generators::erdos_renyi_gnp(200, 0.05, 7).adjacency
+        );
+    }
+
+    #[test]
+    fn barabasi_albert_adds_m_edges_per_vertex() {
+        let (n, m) = (100, 3);
+        let graph = Generators::barabasi_albert(n, m, 5);
+
+        assert_eq!(graph.get_total_of_edges() / 2, m + (n - m - 1) * m);
+        assert!(degrees(&graph).values().all(|degree| *degree >= 1));
+        assert!(is_simple(&graph));
+    }
+
+    #[test]
+    fn watts_strogatz_keeps_the_ring_edge_count() {
+        let (n, k) = (60, 4);
+        let ring = Generators::watts_strogatz(n, k, 0., 2);
+        let rewired = Generators::watts_strogatz(n, k, 0.2, 2);
+
+        assert!(degrees(&ring).values().all(|degree| *degree == k));
+        assert_eq!(rewired.get_total_of_edges() / 2, n * k / 2);
+        assert!(is_simple(&rewired));
+    }
+
+    #[test]
+    fn null_models_preserve_the_degree_sequence() {
+        let graph = Generators::barabasi_albert(80, 2, 13);
+        let swapped = Generators::double_edge_swap(&graph, 5, 13);
+        let configuration = Generators::configuration_model(&graph, 13);
+
+        assert_eq!(degrees(&swapped), degrees(&graph));
+        assert!(is_simple(&swapped));
+        assert!(is_simple(&configuration));
+        // Pontas recusadas só podem diminuir graus
+        let original = degrees(&graph);
+        let configured = degrees(&configuration);
+        assert!(
+            original
+                .iter()
+                .all(|(vertex, degree)| configured[vertex] <= *degree)
+        );
+        assert!(configuration.get_total_of_edges() * 10 >= graph.get_total_of_edges() * 9);
+    }
+}
@@ -0,0 +1,376 @@
+use super::{Generators, PlantedGraph};
+use crate::{
+    graph::{edge::Edge, undirected::Community},
+    utils::random::Random,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct LfrParameters {
+    pub n: usize,
+    pub average_degree: f64,
+    pub max_degree: usize,
+    // Expoentes das leis de potência dos graus (tau1) e dos tamanhos das comunidades (tau2)
+    pub degree_exponent: f64,
+    pub community_exponent: f64,
+    // Fração das arestas de cada vértice que sai da sua comunidade (mu)
+    pub mixing: f64,
+    pub min_community: usize,
+    pub max_community: usize,
+}
+
+impl Default for LfrParameters {
+    fn default() -> Self {
+        Self {
+            n: 1000,
+            average_degree: 20.,
+            max_degree: 50,
+            degree_exponent: 2.5,
+            community_exponent: 1.5,
+            mixing: 0.1,
+            min_community: 20,
+            max_community: 100,
+        }
+    }
+}
+
+impl Generators {
+    // `cliques` cliques de `size` vértices, cada um com uma aresta religada ao clique vizinho
+    pub fn connected_caveman(cliques: usize, size: usize) -> PlantedGraph {
+        assert!(
+            size >= 2,
+            "ERROR: CAVEMAN CLIQUES NEED AT LEAST TWO VERTICES"
+        );
+        let mut planted = Self::caveman(cliques, size);
+        let n = cliques * size;
+
+        if cliques > 1 {
+            for start in (0..n).step_by(size) {
+                planted.graph.remove_edge(&Edge {
+                    from: start,
+                    to: start + 1,
+                });
+                planted
+                    .graph
+                    .push_undirected_edge(start, (start + n - 1) % n);
+            }
+        }
+
+        planted
+    }
+
+    // Caveman em que cada aresta é religada a um vértice aleatório com probabilidade `probability`
+    pub fn relaxed_caveman(
+        cliques: usize,
+        size: usize,
+        probability: f64,
+        seed: u64,
+    ) -> PlantedGraph {
+        let mut random = Random::new(seed);
+        let mut planted = Self::caveman(cliques, size);
+        let n = cliques * size;
+
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for vertex in 0..n {
+            for neighbour in &planted.graph.adjacency[&vertex] {
+                if vertex < *neighbour {
+                    edges.push((vertex, *neighbour));
+                }
+            }
+        }
+        edges.sort_unstable();
+
+        for (u, v) in edges {
+            if !random.gen_bool(probability) {
+                continue;
+            }
+
+            let x = random.gen_range(n);
+            if x == u || planted.graph.has_edge(&u, &x) {
+                continue;
+            }
+
+            planted.graph.remove_edge(&Edge { from: u, to: v });
+            planted.graph.push_undirected_edge(u, x);
+        }
+
+        planted
+    }
+
+    fn caveman(cliques: usize, size: usize) -> PlantedGraph {
+        let mut graph = Self::empty(cliques * size);
+
+        for clique in 0..cliques {
+            let start = clique * size;
+            for u in start..start + size {
+                for v in u + 1..start + size {
+                    graph.push_undirected_edge(u, v);
+                }
+            }
+        }
+
+        PlantedGraph {
+            graph,
+            ground_truth: Self::contiguous_communities(&vec![size; cliques]),
+        }
+    }
+
+    // `groups` grupos de `size` vértices; arestas internas com `p_in` e externas com `p_out`
+    pub fn planted_partition(
+        groups: usize,
+        size: usize,
+        p_in: f64,
+        p_out: f64,
+        seed: u64,
+    ) -> PlantedGraph {
+        let probabilities: Vec<Vec<f64>> = (0..groups)
+            .map(|i| {
+                (0..groups)
+                    .map(|j| if i == j { p_in } else { p_out })
+                    .collect()
+            })
+            .collect();
+
+        Self::stochastic_block_model(&vec![size; groups], &probabilities, seed)
+    }
+
+    // `probabilities[i][j]` é a probabilidade de aresta entre um vértice do bloco i e um do j
+    pub fn stochastic_block_model(
+        sizes: &[usize],
+        probabilities: &[Vec<f64>],
+        seed: u64,
+    ) -> PlantedGraph {
+        assert!(
+            probabilities.len() == sizes.len()
+                && probabilities.iter().all(|row| row.len() == sizes.len()),
+            "ERROR: PROBABILITY MATRIX MUST BE {0}x{0}",
+            sizes.len()
+        );
+
+        let mut random = Random::new(seed);
+        let n: usize = sizes.iter().sum();
+        let mut graph = Self::empty(n);
+        let block: Vec<usize> = sizes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, size)| std::iter::repeat_n(i, *size))
+            .collect();
+
+        for u in 0..n {
+            for v in u + 1..n {
+                if random.gen_bool(probabilities[block[u]][block[v]]) {
+                    graph.push_undirected_edge(u, v);
+                }
+            }
+        }
+
+        PlantedGraph {
+            graph,
+            ground_truth: Self::contiguous_communities(sizes),
+        }
+    }
+
+    /*
+     *  Benchmark LFR (Lancichinetti–Fortunato–Radicchi). Graus e tamanhos de comunidade seguem
+     *  leis de potência; cada vértice recebe (1 - mu) * k arestas internas e mu * k externas,
+     *  ligadas por pareamento aleatório de pontas. Pontas que formariam laços ou arestas
+     *  repetidas são descartadas, então os graus obtidos podem ficar um pouco abaixo dos
+     *  amostrados.
+     */
+    pub fn lfr(parameters: &LfrParameters, seed: u64) -> PlantedGraph {
+        let mut random = Random::new(seed);
+        let n = parameters.n;
+        let max_degree = parameters.max_degree.min(n.saturating_sub(1)).max(1);
+        let max_community = parameters.max_community.min(n).max(1);
+        let min_community = parameters.min_community.clamp(1, max_community);
+
+        // Grau mínimo cuja média da lei de potência mais se aproxima do grau médio pedido
+        let min_degree = (1..=max_degree)
+            .min_by(|x, y| {
+                let mean = |min: usize| {
+                    let weights =
+                        (min..=max_degree).map(|k| (k as f64).powf(-parameters.degree_exponent));
+                    let total: f64 = weights.clone().sum();
+                    (min..=max_degree)
+                        .zip(weights)
+                        .map(|(k, w)| k as f64 * w)
+                        .sum::<f64>()
+                        / total
+                };
+                (mean(*x) - parameters.average_degree)
+                    .abs()
+                    .total_cmp(&(mean(*y) - parameters.average_degree).abs())
+            })
+            .unwrap_or(1);
+
+        let degree_cdf = Self::power_law_cdf(min_degree, max_degree, parameters.degree_exponent);
+        let degrees: Vec<usize> = (0..n)
+            .map(|_| Self::sample_cdf(&degree_cdf, min_degree, &mut random))
+            .collect();
+
+        let sizes = Self::community_sizes(n, min_community, max_community, parameters, &mut random);
+
+        let mut internal_degrees: Vec<usize> = degrees
+            .iter()
+            .map(|k| ((1. - parameters.mixing) * *k as f64).round() as usize)
+            .collect();
+
+        // Vértices de maior grau interno escolhem comunidade primeiro
+        let mut order: Vec<usize> = (0..n).collect();
+        random.shuffle(&mut order);
+        order.sort_by_key(|vertex| std::cmp::Reverse(internal_degrees[*vertex]));
+
+        let mut free: Vec<usize> = sizes.clone();
+        let mut membership: Vec<usize> = vec![0; n];
+        for vertex in order {
+            let fitting: Vec<usize> = (0..sizes.len())
+                .filter(|c| free[*c] > 0 && sizes[*c] > internal_degrees[vertex])
+                .collect();
+
+            let community = match random.choose(&fitting) {
+                Some(community) => *community,
+                None => (0..sizes.len())
+                    .filter(|c| free[*c] > 0)
+                    .max_by_key(|c| sizes[*c])
+                    .unwrap(),
+            };
+
+            internal_degrees[vertex] = internal_degrees[vertex].min(sizes[community] - 1);
+            membership[vertex] = community;
+            free[community] -= 1;
+        }
+
+        let mut graph = Self::empty(n);
+        let mut ground_truth: Vec<Community<usize>> = vec![Community::new(); sizes.len()];
+        let mut internal_stubs: Vec<Vec<usize>> = vec![Vec::new(); sizes.len()];
+        let mut external_stubs: Vec<usize> = Vec::new();
+
+        for vertex in 0..n {
+            let community = membership[vertex];
+            ground_truth[community].insert(vertex);
+            internal_stubs[community].extend(std::iter::repeat_n(vertex, internal_degrees[vertex]));
+            external_stubs.extend(std::iter::repeat_n(
+                vertex,
+                degrees[vertex].saturating_sub(internal_degrees[vertex]),
+            ));
+        }
+
+        for stubs in internal_stubs {
+            Self::match_stubs(&mut graph, stubs, &mut random, |_, _| true);
+        }
+        Self::match_stubs(&mut graph, external_stubs, &mut random, |u, v| {
+            membership[u] != membership[v]
+        });
+
+        PlantedGraph {
+            graph,
+            ground_truth,
+        }
+    }
+
+    fn community_sizes(
+        n: usize,
+        min_community: usize,
+        max_community: usize,
+        parameters: &LfrParameters,
+        random: &mut Random,
+    ) -> Vec<usize> {
+        let cdf = Self::power_law_cdf(min_community, max_community, parameters.community_exponent);
+        let mut sizes: Vec<usize> = Vec::new();
+        let mut total = 0;
+
+        while total < n {
+            let size = Self::sample_cdf(&cdf, min_community, random).min(n - total);
+            sizes.push(size);
+            total += size;
+        }
+
+        // A última comunidade pode ter ficado pequena demais: seus vértices são redistribuídos
+        if sizes.len() > 1 && *sizes.last().unwrap() < min_community {
+            let leftover = sizes.pop().unwrap();
+            for i in 0..leftover {
+                let count = sizes.len();
+                sizes[i % count] += 1;
+            }
+        }
+
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edges(planted: &PlantedGraph) -> usize {
+        planted.graph.get_total_of_edges() / 2
+    }
+
+    fn covers_all_vertices(planted: &PlantedGraph) -> bool {
+        let total: usize = planted.ground_truth.iter().map(Community::len).sum();
+        let union: Community<usize> = planted.ground_truth.iter().flatten().copied().collect();
+        total == planted.graph.vertices.len() && union == planted.graph.vertices
+    }
+
+    #[test]
+    fn connected_caveman_keeps_clique_edges_and_degrees() {
+        let planted = Generators::connected_caveman(4, 5);
+
+        assert_eq!(planted.graph.vertices.len(), 20);
+        assert_eq!(edges(&planted), 4 * 10);
+        // Cada clique troca uma aresta interna por uma ligação ao clique anterior
+        assert!(
+            planted
+                .graph
+                .adjacency
+                .values()
+                .all(|neighbourhood| (3..=5).contains(&neighbourhood.len()))
+        );
+        assert_eq!(planted.graph.get_communities().len(), 1);
+        assert_eq!(planted.ground_truth.len(), 4);
+        assert!(covers_all_vertices(&planted));
+    }
+
+    #[test]
+    fn relaxed_caveman_rewires_without_changing_the_edge_count() {
+        let planted = Generators::relaxed_caveman(5, 6, 0.3, 11);
+        let again = Generators::relaxed_caveman(5, 6, 0.3, 11);
+
+        assert_eq!(edges(&planted), 5 * 15);
+        assert_eq!(planted.graph.adjacency, again.graph.adjacency);
+        assert!(covers_all_vertices(&planted));
+    }
+
+    #[test]
+    fn planted_partition_with_certain_probabilities_gives_disjoint_cliques() {
+        let planted = Generators::planted_partition(3, 7, 1., 0., 5);
+
+        assert_eq!(edges(&planted), 3 * 21);
+        assert_eq!(planted.graph.get_communities().len(), 3);
+        assert!(covers_all_vertices(&planted));
+    }
+
+    #[test]
+    fn lfr_is_reproducible_and_simple() {
+        let parameters = LfrParameters {
+            n: 300,
+            average_degree: 10.,
+            max_degree: 30,
+            min_community: 20,
+            max_community: 60,
+            ..Default::default()
+        };
+        let planted = Generators::lfr(&parameters, 9);
+        let again = Generators::lfr(&parameters, 9);
+
+        assert_eq!(planted.graph.adjacency, again.graph.adjacency);
+        assert_eq!(planted.graph.vertices.len(), 300);
+        assert!(covers_all_vertices(&planted));
+        for (vertex, neighbourhood) in &planted.graph.adjacency {
+            assert!(!neighbourhood.contains(vertex));
+            assert!(neighbourhood.len() <= 30);
+        }
+        for community in &planted.ground_truth {
+            assert!((20..=60).contains(&community.len()));
+        }
+    }
+}
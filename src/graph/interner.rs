@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{
+    undirected::{Community, UndirectedGraph, communities::partition::Partition},
+    utils::Utils,
+};
+
+/*
+ *  Associa rótulos externos a identificadores densos 0..n e vice-versa. Os algoritmos rodam
+ *  sobre `UndirectedGraph<usize>`, sem clonar rótulos, e os resultados voltam aos rótulos
+ *  originais só na saída.
+ */
+#[derive(Debug, Clone)]
+pub struct Interner<T> {
+    labels: Vec<T>,
+    ids: HashMap<T, usize>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            labels: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Interner<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Identificador de `label`, criando um novo se o rótulo ainda não foi visto
+    pub fn intern(&mut self, label: T) -> usize {
+        if let Some(id) = self.ids.get(&label) {
+            return *id;
+        }
+        self.labels.push(label.clone());
+        self.ids.insert(label, self.labels.len() - 1);
+        self.labels.len() - 1
+    }
+
+    pub fn get_id(&self, label: &T) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn get_label(&self, id: usize) -> Option<&T> {
+        self.labels.get(id)
+    }
+
+    pub fn get_labels(&self) -> &[T] {
+        &self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    fn resolve(&self, id: &usize) -> T {
+        self.labels
+            .get(*id)
+            .cloned()
+            .expect("ERROR: ID WAS NOT INTERNED")
+    }
+
+    pub fn resolve_community(&self, community: &Community<usize>) -> Community<T> {
+        community.iter().map(|id| self.resolve(id)).collect()
+    }
+
+    pub fn resolve_communities(&self, communities: &[Community<usize>]) -> Vec<Community<T>> {
+        communities
+            .iter()
+            .map(|community| self.resolve_community(community))
+            .collect()
+    }
+
+    // Valores por identificador, como os das centralidades, de volta aos rótulos
+    pub fn resolve_values<V>(&self, values: HashMap<usize, V>) -> HashMap<T, V> {
+        values
+            .into_iter()
+            .map(|(id, value)| (self.resolve(&id), value))
+            .collect()
+    }
+
+    // Inverso de `resolve_communities`; rótulos que nunca foram internados ficam de fora
+    pub fn get_community_ids(&self, communities: &[Community<T>]) -> Vec<Community<usize>> {
+        communities
+            .iter()
+            .map(|community| {
+                community
+                    .iter()
+                    .filter_map(|label| self.get_id(label))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<T> FromIterator<T> for Interner<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(labels: I) -> Self {
+        let mut interner = Self::new();
+        for label in labels {
+            interner.intern(label);
+        }
+        interner
+    }
+}
+
+impl<T> Interner<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    /*
+     *  Os identificadores seguem a ordem estável dos rótulos, então o modo determinístico dos
+     *  algoritmos percorre os vértices na mesma ordem que percorreria com os rótulos
+     */
+    pub fn from_graph(graph: &UndirectedGraph<T>) -> (Self, UndirectedGraph<usize>) {
+        let interner: Self = graph.get_ordered_vertices(None).into_iter().collect();
+
+        let mut interned = UndirectedGraph::new();
+        for (id, label) in interner.labels.iter().enumerate() {
+            interned.push_vertex(id);
+            if let Some(neighbourhood) = graph.get_neighbourhood(label) {
+                let neighbourhood = neighbourhood.iter().map(|n| interner.ids[n]).collect();
+                interned.adjacency.insert(id, neighbourhood);
+            }
+        }
+
+        (interner, interned)
+    }
+
+    /*
+     *  Renumera os identificadores na ordem estável dos rótulos, a mesma de `from_graph`, para
+     *  que o modo determinístico não dependa da ordem em que os rótulos foram internados.
+     *  Retorna o novo identificador de cada identificador antigo.
+     */
+    pub fn sort_stable(&mut self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.labels.len()).collect();
+        order.sort_by_cached_key(|id| Utils::stable_key(&self.labels[*id]));
+
+        let mut positions = vec![0; order.len()];
+        for (position, id) in order.iter().enumerate() {
+            positions[*id] = position;
+        }
+
+        let mut labels: Vec<Option<T>> = std::mem::take(&mut self.labels)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.labels = order.iter().filter_map(|id| labels[*id].take()).collect();
+        self.ids.values_mut().for_each(|id| *id = positions[*id]);
+
+        positions
+    }
+
+    pub fn resolve_graph(&self, graph: &UndirectedGraph<usize>) -> UndirectedGraph<T> {
+        let mut resolved = UndirectedGraph::new();
+        for id in &graph.vertices {
+            resolved.push_vertex(self.resolve(id));
+        }
+        for (id, neighbourhood) in &graph.adjacency {
+            resolved.adjacency.insert(
+                self.resolve(id),
+                neighbourhood.iter().map(|n| self.resolve(n)).collect(),
+            );
+        }
+        resolved
+    }
+
+    pub fn resolve_partition(&self, partition: Partition<usize>) -> Partition<T> {
+        Partition {
+            vertices: partition
+                .vertices
+                .iter()
+                .map(|id| self.resolve(id))
+                .collect(),
+            membership: partition.membership,
+            communities: self.resolve_communities(&partition.communities),
+            quality: partition.quality,
+            hierarchy: partition.hierarchy.map(|levels| {
+                levels
+                    .iter()
+                    .map(|level| self.resolve_communities(level))
+                    .collect()
+            }),
+            truncated: partition.truncated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn communities_and_values_round_trip_through_ids() {
+        let interner: Interner<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let communities: Vec<Community<String>> = vec![
+            ["a", "c"].iter().map(|s| s.to_string()).collect(),
+            ["b", "z"].iter().map(|s| s.to_string()).collect(),
+        ];
+
+        let ids = interner.get_community_ids(&communities);
+        assert_eq!(ids, vec![Community::from([0, 2]), Community::from([1])]);
+        assert_eq!(interner.resolve_communities(&ids)[0], communities[0]);
+
+        let values = interner.resolve_values(HashMap::from([(0, 1.5), (2, 3.)]));
+        assert_eq!(
+            values,
+            HashMap::from([("a".to_string(), 1.5), ("c".to_string(), 3.)])
+        );
+    }
+}
@@ -1,6 +1,8 @@
 mod betweenness;
 mod edge;
+pub mod generators;
+pub mod interner;
 mod path;
 pub mod undirected;
-mod utils;
+pub mod utils;
 mod vertices;
@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use rayon::prelude::*;
+
+use super::{UndirectedGraph, indexed::IndexedGraph};
+use crate::{
+    graph::{betweenness::Betweenness, edge::Edge},
+    utils::random::Random,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BetweennessSampling {
+    // Acumulação de Brandes a partir de `samples` fontes uniformes, extrapolada por n/samples
+    Pivots { samples: usize, seed: u64 },
+    /*
+     *  Riondato–Kornaropoulos: amostra pares (s, t) e um menor caminho uniforme entre eles. A
+     *  quantidade de amostras se adapta ao diâmetro do grafo e garante erro no máximo
+     *  `epsilon` (na escala normalizada) com probabilidade 1 - `delta`.
+     */
+    Adaptive { epsilon: f64, delta: f64, seed: u64 },
+}
+
+impl BetweennessSampling {
+    pub fn get_seed(&self) -> u64 {
+        match self {
+            Self::Pivots { seed, .. } | Self::Adaptive { seed, .. } => *seed,
+        }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        match self {
+            Self::Pivots { samples, .. } => Self::Pivots { samples, seed },
+            Self::Adaptive { epsilon, delta, .. } => Self::Adaptive {
+                epsilon,
+                delta,
+                seed,
+            },
+        }
+    }
+}
+
+// Estimativas na mesma escala do betweenness exato (pares não ordenados)
+pub(crate) struct SampledBetweenness {
+    pub vertices: Vec<f64>,
+    pub edges: HashMap<(usize, usize), f64>,
+}
+
+type Accumulator = (Vec<f64>, HashMap<(usize, usize), f64>);
+
+fn merge_accumulators(mut x: Accumulator, y: Accumulator) -> Accumulator {
+    x.0.iter_mut().zip(y.0).for_each(|(x, y)| *x += y);
+    for (edge, value) in y.1 {
+        *x.1.entry(edge).or_insert(0.) += value;
+    }
+    x
+}
+
+fn edge_key(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+impl<T> IndexedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone,
+{
+    /*
+     *  Limite superior do diâmetro em vértices: em cada componente, a soma das duas maiores
+     *  distâncias a partir de um vértice qualquer, mais um
+     */
+    pub(crate) fn get_vertex_diameter_bound(&self) -> usize {
+        let mut visited = vec![false; self.len()];
+        let mut bound = 1;
+
+        for start in 0..self.len() {
+            if visited[start] {
+                continue;
+            }
+            let dag = self.shortest_paths_dag(start);
+            let mut distances: Vec<usize> = dag.order.iter().map(|v| dag.distance[*v]).collect();
+            dag.order.iter().for_each(|v| visited[*v] = true);
+            distances.sort_unstable_by(|x, y| y.cmp(x));
+
+            let two_largest = distances.iter().take(2).sum::<usize>();
+            bound = bound.max(two_largest + 1);
+        }
+
+        bound
+    }
+
+    pub(crate) fn get_sampled_betweenness(
+        &self,
+        sampling: BetweennessSampling,
+    ) -> SampledBetweenness {
+        let n = self.len();
+        let empty = || (vec![0.; n], HashMap::new());
+        if n < 2 {
+            return SampledBetweenness {
+                vertices: vec![0.; n],
+                edges: HashMap::new(),
+            };
+        }
+
+        match sampling {
+            BetweennessSampling::Pivots { samples, seed } => {
+                let mut sources: Vec<usize> = (0..n).collect();
+                Random::new(seed).shuffle(&mut sources);
+                sources.truncate(samples.clamp(1, n));
+
+                /*
+                 *  As contribuições de cada fonte são fracionárias: somá-las na ordem em que o
+                 *  rayon termina mudaria os arredondamentos (e os empates do Girvan–Newman) de
+                 *  uma execução para outra. Por isso elas são somadas na ordem das fontes.
+                 */
+                let contributions: Vec<Accumulator> = sources
+                    .par_iter()
+                    .map(|&source| {
+                        let mut acc = empty();
+                        let delta = self.brandes_from(source, |u, v, contribution| {
+                            *acc.1.entry(edge_key(u, v)).or_insert(0.) += contribution;
+                        });
+                        for (vertex, value) in delta.into_iter().enumerate() {
+                            if vertex != source {
+                                acc.0[vertex] += value;
+                            }
+                        }
+                        acc
+                    })
+                    .collect();
+                let (mut vertices, mut edges) =
+                    contributions.into_iter().fold(empty(), merge_accumulators);
+
+                // Cada fonte conta os pares a partir de uma ponta só
+                let scale = n as f64 / (2. * sources.len() as f64);
+                vertices.iter_mut().for_each(|value| *value *= scale);
+                edges.values_mut().for_each(|value| *value *= scale);
+
+                SampledBetweenness { vertices, edges }
+            }
+            BetweennessSampling::Adaptive {
+                epsilon,
+                delta,
+                seed,
+            } => {
+                if epsilon <= 0. || delta <= 0. || delta >= 1. {
+                    panic!("ERROR: EPSILON MUST BE POSITIVE AND DELTA MUST BE IN (0, 1)");
+                }
+
+                let diameter = self.get_vertex_diameter_bound().saturating_sub(2).max(1);
+                let samples = (0.5 / (epsilon * epsilon)
+                    * ((diameter as f64).log2().floor() + 1. + (1. / delta).ln()))
+                .ceil() as usize;
+
+                /*
+                 *  Sementes por amostra para que os caminhos sorteados não dependam do
+                 *  escalonamento do rayon. A soma paralela abaixo também não depende, mas só
+                 *  porque cada amostra soma exatamente 1.0, e somas de inteiros são exatas.
+                 */
+                let mut random = Random::new(seed);
+                let pairs: Vec<(usize, usize, u64)> = (0..samples)
+                    .map(|_| {
+                        let source = random.gen_range(n);
+                        let target = (source + 1 + random.gen_range(n - 1)) % n;
+                        (source, target, random.next_u64())
+                    })
+                    .collect();
+
+                let (mut vertices, mut edges) = pairs
+                    .par_iter()
+                    .fold(empty, |mut acc, &(source, target, seed)| {
+                        self.sample_shortest_path(source, target, seed, |u, v| {
+                            *acc.1.entry(edge_key(u, v)).or_insert(0.) += 1.;
+                            if u != source {
+                                acc.0[u] += 1.;
+                            }
+                        });
+                        acc
+                    })
+                    .reduce(empty, merge_accumulators);
+
+                // Fração estimada dos pares levada para a escala de n(n - 1)/2 pares
+                let scale = (n * (n - 1)) as f64 / (2. * samples as f64);
+                vertices.iter_mut().for_each(|value| *value *= scale);
+                edges.values_mut().for_each(|value| *value *= scale);
+
+                SampledBetweenness { vertices, edges }
+            }
+        }
+    }
+
+    /*
+     *  Sorteia um menor caminho uniforme de `source` até `target`, voltando de `target` e
+     *  escolhendo cada predecessor com probabilidade proporcional a sigma. `on_edge(u, v)`
+     *  recebe as arestas do caminho no sentido de `target` para `source`.
+     */
+    fn sample_shortest_path(
+        &self,
+        source: usize,
+        target: usize,
+        seed: u64,
+        mut on_edge: impl FnMut(usize, usize),
+    ) {
+        let dag = self.shortest_paths_dag(source);
+        if dag.distance[target] == usize::MAX {
+            return;
+        }
+
+        let mut random = Random::new(seed);
+        let mut current = target;
+        while current != source {
+            let predecessors: Vec<usize> = self.adjacency[current]
+                .iter()
+                .copied()
+                .filter(|p| dag.distance[*p] != usize::MAX)
+                .filter(|p| dag.distance[*p] + 1 == dag.distance[current])
+                .collect();
+
+            let mut threshold = random.next_f64() * dag.sigma[current];
+            let mut chosen = predecessors[predecessors.len() - 1];
+            for predecessor in predecessors {
+                threshold -= dag.sigma[predecessor];
+                if threshold < 0. {
+                    chosen = predecessor;
+                    break;
+                }
+            }
+
+            on_edge(chosen, current);
+            current = chosen;
+        }
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn get_approximate_vertices_betweenness(
+        &self,
+        sampling: BetweennessSampling,
+        normalized: bool,
+    ) -> HashMap<T, f64> {
+        let indexed = self.to_indexed();
+        let n = indexed.len() as f64;
+        let mut values = indexed.get_sampled_betweenness(sampling).vertices;
+
+        if normalized && n > 2. {
+            let pairs = (n - 1.) * (n - 2.) / 2.;
+            values.iter_mut().for_each(|value| *value /= pairs);
+        }
+
+        indexed.label(values)
+    }
+
+    // Mesmo formato de `get_edges_betweenness`, para uso no Girvan–Newman
+    pub fn get_approximate_edges_betweenness(
+        &self,
+        sampling: BetweennessSampling,
+    ) -> Betweenness<T> {
+        let indexed = self.to_indexed();
+        let mut betweenness = Betweenness::default();
+
+        for ((u, v), value) in indexed.get_sampled_betweenness(sampling).edges {
+            betweenness.insert_edge(
+                Edge {
+                    from: indexed.vertices[u].clone(),
+                    to: indexed.vertices[v].clone(),
+                },
+                value,
+            );
+        }
+
+        betweenness
+    }
+}
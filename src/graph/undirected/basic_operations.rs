@@ -1,10 +1,12 @@
-use super::{Community, UndirectedGraph};
+use super::{Community, UndirectedGraph, indexed::IndexedGraph};
 use crate::graph::{
     betweenness::Betweenness,
     edge::Edge,
     path::{Path, Paths},
+    utils::Utils,
     vertices::{VertexData, VerticesData},
 };
+use crate::utils::random::Random;
 use rayon::prelude::*;
 use std::{
     collections::{HashMap, HashSet},
@@ -24,6 +26,10 @@ where
         }
     }
 
+    pub fn get_ordered_vertices(&self, random: Option<&mut Random>) -> Vec<T> {
+        Utils::stable_order(self.vertices.iter().cloned().collect(), random)
+    }
+
     pub fn push_vertex(&mut self, vertex: T) {
         self.vertices.insert(vertex);
     }
@@ -39,18 +45,51 @@ where
             .or_insert(vec![edge.to.clone()]);
     }
 
+    // Insere a aresta nos dois sentidos e registra os dois vértices
+    pub fn push_undirected_edge(&mut self, from: T, to: T) {
+        self.push_edge(&Edge {
+            from: from.clone(),
+            to: to.clone(),
+        });
+        self.push_edge(&Edge {
+            from: to.clone(),
+            to: from.clone(),
+        });
+        self.push_vertex(from);
+        self.push_vertex(to);
+    }
+
+    pub fn has_edge(&self, from: &T, to: &T) -> bool {
+        self.get_neighbourhood(from)
+            .is_some_and(|neighbourhood| neighbourhood.contains(to))
+    }
+
     pub fn remove_edge(&mut self, edge: &Edge<T>) {
-        if let Some(neighbourhood) = self.adjacency.get_mut(&edge.from) {
-            if let Some(position) = neighbourhood.iter().position(|vertex| *vertex == edge.to) {
-                neighbourhood.swap_remove(position);
-            }
+        if let Some(neighbourhood) = self.adjacency.get_mut(&edge.from)
+            && let Some(position) = neighbourhood.iter().position(|vertex| *vertex == edge.to)
+        {
+            neighbourhood.swap_remove(position);
         }
 
-        if let Some(neighbourhood) = self.adjacency.get_mut(&edge.to) {
-            if let Some(position) = neighbourhood.iter().position(|vertex| *vertex == edge.from) {
-                neighbourhood.swap_remove(position);
+        if let Some(neighbourhood) = self.adjacency.get_mut(&edge.to)
+            && let Some(position) = neighbourhood.iter().position(|vertex| *vertex == edge.from)
+        {
+            neighbourhood.swap_remove(position);
+        }
+    }
+
+    pub fn remove_vertex(&mut self, vertex: &T) {
+        if let Some(neighbourhood) = self.adjacency.remove(vertex) {
+            for neighbour in neighbourhood {
+                if let Some(neighbourhood) = self.adjacency.get_mut(&neighbour)
+                    && let Some(position) = neighbourhood.iter().position(|v| v == vertex)
+                {
+                    neighbourhood.swap_remove(position);
+                }
             }
         }
+
+        self.vertices.remove(vertex);
     }
 
     pub fn has_edges(&self) -> bool {
@@ -90,6 +129,18 @@ where
     }
 
     pub fn get_modularity(&self, communities: Vec<Community<T>>) -> f64 {
+        self.get_modularity_with_resolution(communities, 1.)
+    }
+
+    /*
+     *  Modularidade de Reichardt–Bornholdt: o termo nulo é multiplicado por `resolution`.
+     *  Valores maiores que 1 favorecem comunidades menores.
+     */
+    pub fn get_modularity_with_resolution(
+        &self,
+        communities: Vec<Community<T>>,
+        resolution: f64,
+    ) -> f64 {
         let mut modularity_value = 0.;
         let total_of_edges = self.get_total_of_edges() as f64;
 
@@ -108,58 +159,64 @@ where
             }
 
             modularity_value += (number_of_intra_community_links as f64 / total_of_edges)
-                - (sum_of_degrees as f64 / total_of_edges).powf(2.);
+                - resolution * (sum_of_degrees as f64 / total_of_edges).powf(2.);
         }
 
         modularity_value
     }
 
-    pub fn get_number_of_triangles(&self, neighbourhood: &[T]) -> usize {
-        let mut number_of_triangles = 0;
-
-        for (i, neighbour_u) in neighbourhood.iter().enumerate() {
-            for neighbour_v in neighbourhood.iter().skip(i) {
-                if let Some(u_neighbourhood) = self.get_neighbourhood(neighbour_u) {
-                    let u_neighbourhood = u_neighbourhood;
-                    let u_neighbourhood_set: HashSet<&T> = u_neighbourhood.iter().collect();
-                    if neighbour_u != neighbour_v && u_neighbourhood_set.contains(neighbour_v) {
-                        number_of_triangles += 1;
-                        continue;
-                    }
-                }
-            }
-        }
-
-        number_of_triangles
+    // Constant Potts Model: soma de e_c - resolution * n_c(n_c - 1)/2 sobre as comunidades
+    pub fn get_constant_potts(&self, communities: Vec<Community<T>>, resolution: f64) -> f64 {
+        communities
+            .iter()
+            .map(|community| {
+                let number_of_intra_community_links = community
+                    .iter()
+                    .filter_map(|vertex| self.get_neighbourhood(vertex))
+                    .flat_map(|neighbourhood| neighbourhood.iter())
+                    .filter(|n| community.contains(n))
+                    .count()
+                    / 2;
+                let size = community.len() as f64;
+
+                number_of_intra_community_links as f64 - resolution * size * (size - 1.) / 2.
+            })
+            .sum()
     }
 
-    pub fn get_clustering_coefficients(&self) -> Vec<(&T, f64)> {
-        let mut coefficients: Vec<(&T, f64)> = Vec::new();
-
-        for vertex in self.vertices.iter() {
-            if let Some(neighbourhood) = self.get_neighbourhood(vertex) {
-                if neighbourhood.len() <= 1 {
-                    coefficients.push((vertex, 0.));
-                    continue;
-                }
+    // Quantidade de pares de vértices de `neighbourhood` ligados por uma aresta
+    pub fn get_number_of_triangles(&self, neighbourhood: &[T]) -> usize {
+        let neighbourhood_set: HashSet<&T> = neighbourhood.iter().collect();
 
-                let number_of_connected_triples: usize =
-                    (neighbourhood.len() * (neighbourhood.len() - 1)) / 2;
-                let number_of_triangles: usize = self.get_number_of_triangles(neighbourhood);
+        neighbourhood_set
+            .iter()
+            .filter_map(|neighbour| self.get_neighbourhood(neighbour))
+            .flat_map(|u_neighbourhood| u_neighbourhood.iter())
+            .filter(|vertex| neighbourhood_set.contains(vertex))
+            .count()
+            / 2
+    }
 
-                coefficients.push((
-                    vertex,
-                    number_of_triangles as f64 / number_of_connected_triples as f64,
-                ));
-            }
-        }
+    /*
+     *  Coeficientes locais calculados a partir da contagem paralela de triângulos. `indexed` é
+     *  a cópia indexada deste grafo, montada uma vez por quem chama.
+     */
+    pub fn get_clustering_coefficients(&self, indexed: &IndexedGraph<T>) -> Vec<(&T, f64)> {
+        let triangles = indexed.count_triangles();
+        let coefficients = indexed.get_clustering_coefficients(&triangles);
 
-        coefficients
+        indexed
+            .vertices
+            .iter()
+            .zip(coefficients)
+            .filter(|(vertex, _)| self.adjacency.contains_key(*vertex))
+            .filter_map(|(vertex, coefficient)| Some((self.vertices.get(vertex)?, coefficient)))
+            .collect()
     }
 
-    pub fn get_highest_clustering_coefficients(&self) -> (&T, f64) {
+    pub fn get_highest_clustering_coefficients(&self, indexed: &IndexedGraph<T>) -> (&T, f64) {
         match self
-            .get_clustering_coefficients()
+            .get_clustering_coefficients(indexed)
             .iter()
             .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(std::cmp::Ordering::Equal))
         {
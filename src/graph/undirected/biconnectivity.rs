@@ -0,0 +1,281 @@
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{Community, UndirectedGraph, indexed::IndexedGraph};
+use crate::graph::edge::Edge;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Biconnectivity {
+    pub bridges: Vec<(usize, usize)>,
+    pub articulation_points: Vec<usize>,
+    // Vértices de cada componente biconexa; pontos de articulação aparecem em mais de uma
+    pub components: Vec<Vec<usize>>,
+}
+
+impl<T> IndexedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone,
+{
+    /*
+     *  DFS de Tarjan (iterativa, para não estourar a pilha em grafos grandes) com `low[v]`, o
+     *  menor tempo de descoberta alcançável a partir da subárvore de v por uma aresta de
+     *  retorno. A aresta (p, v) é ponte se low[v] > disc[p], e p separa a subárvore de v se
+     *  low[v] >= disc[p]; nesse caso as arestas empilhadas desde (p, v) formam uma
+     *  componente biconexa.
+     */
+    pub(crate) fn get_biconnectivity(&self) -> Biconnectivity {
+        let n = self.len();
+        let mut discovery = vec![usize::MAX; n];
+        let mut low = vec![usize::MAX; n];
+        let mut is_articulation = vec![false; n];
+        let mut timer = 0;
+        let mut result = Biconnectivity::default();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+
+        for root in 0..n {
+            if discovery[root] != usize::MAX {
+                continue;
+            }
+            discovery[root] = timer;
+            low[root] = timer;
+            timer += 1;
+
+            let mut root_children = 0;
+            let mut stack: Vec<(usize, usize, usize)> = vec![(root, usize::MAX, 0)];
+
+            while let Some((vertex, parent, next)) = stack.last_mut() {
+                let (vertex, parent) = (*vertex, *parent);
+                if let Some(&neighbour) = self.adjacency[vertex].get(*next) {
+                    *next += 1;
+                    if neighbour == parent || neighbour == vertex {
+                        continue;
+                    }
+
+                    if discovery[neighbour] == usize::MAX {
+                        discovery[neighbour] = timer;
+                        low[neighbour] = timer;
+                        timer += 1;
+                        edges.push((vertex, neighbour));
+                        stack.push((neighbour, vertex, 0));
+                    } else if discovery[neighbour] < discovery[vertex] {
+                        low[vertex] = low[vertex].min(discovery[neighbour]);
+                        edges.push((vertex, neighbour));
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                if parent == usize::MAX {
+                    continue;
+                }
+
+                low[parent] = low[parent].min(low[vertex]);
+                if low[vertex] > discovery[parent] {
+                    result
+                        .bridges
+                        .push((parent.min(vertex), parent.max(vertex)));
+                }
+                if low[vertex] >= discovery[parent] {
+                    if parent == root {
+                        root_children += 1;
+                    } else {
+                        is_articulation[parent] = true;
+                    }
+
+                    let mut component: HashSet<usize> = HashSet::new();
+                    while let Some((u, v)) = edges.pop() {
+                        component.insert(u);
+                        component.insert(v);
+                        if (u, v) == (parent, vertex) {
+                            break;
+                        }
+                    }
+                    let mut component: Vec<usize> = component.into_iter().collect();
+                    component.sort_unstable();
+                    result.components.push(component);
+                }
+            }
+
+            if root_children > 1 {
+                is_articulation[root] = true;
+            }
+        }
+
+        result.articulation_points = (0..n).filter(|v| is_articulation[*v]).collect();
+        result
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    // As pontas de cada ponte seguem a ordem estável de `Utils`
+    pub fn get_bridges(&self) -> Vec<Edge<T>> {
+        let indexed = self.to_indexed();
+
+        indexed
+            .get_biconnectivity()
+            .bridges
+            .into_iter()
+            .map(|(u, v)| Edge {
+                from: indexed.vertices[u].clone(),
+                to: indexed.vertices[v].clone(),
+            })
+            .collect()
+    }
+
+    pub fn get_articulation_points(&self) -> Vec<T> {
+        let indexed = self.to_indexed();
+
+        indexed
+            .get_biconnectivity()
+            .articulation_points
+            .into_iter()
+            .map(|vertex| indexed.vertices[vertex].clone())
+            .collect()
+    }
+
+    // Vértices isolados não pertencem a nenhuma componente biconexa
+    pub fn get_biconnected_components(&self) -> Vec<Community<T>> {
+        let indexed = self.to_indexed();
+
+        indexed
+            .get_biconnectivity()
+            .components
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|vertex| indexed.vertices[vertex].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /*
+     *  Remove todas as pontes e devolve cada componente conexa restante como um grafo
+     *  independente (vértices isolados viram grafos de um vértice só)
+     */
+    pub fn split_at_bridges(&self) -> Vec<UndirectedGraph<T>> {
+        let mut graph = self.clone();
+        for bridge in self.get_bridges() {
+            graph.remove_edge(&bridge);
+        }
+
+        graph
+            .get_connected_components()
+            .iter()
+            .map(|component| graph.induced_subgraph(component))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_bridges(graph: &UndirectedGraph<usize>) -> Vec<(usize, usize)> {
+        let mut bridges: Vec<(usize, usize)> = graph
+            .get_bridges()
+            .into_iter()
+            .map(|edge| (edge.from.min(edge.to), edge.from.max(edge.to)))
+            .collect();
+        bridges.sort_unstable();
+        bridges
+    }
+
+    fn sorted_articulation_points(graph: &UndirectedGraph<usize>) -> Vec<usize> {
+        let mut points = graph.get_articulation_points();
+        points.sort_unstable();
+        points
+    }
+
+    fn sorted_components(graph: &UndirectedGraph<usize>) -> Vec<Vec<usize>> {
+        let mut components: Vec<Vec<usize>> = graph
+            .get_biconnected_components()
+            .into_iter()
+            .map(|component| {
+                let mut component: Vec<usize> = component.into_iter().collect();
+                component.sort_unstable();
+                component
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn path() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3]]);
+
+        assert_eq!(sorted_bridges(&graph), vec![(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(sorted_articulation_points(&graph), vec![1, 2]);
+        assert_eq!(
+            sorted_components(&graph),
+            vec![vec![0, 1], vec![1, 2], vec![2, 3]]
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3], [3, 4], [4, 0]]);
+
+        assert!(sorted_bridges(&graph).is_empty());
+        assert!(sorted_articulation_points(&graph).is_empty());
+        assert_eq!(sorted_components(&graph), vec![vec![0, 1, 2, 3, 4]]);
+    }
+
+    #[test]
+    fn triangles_joined_by_a_bridge() {
+        let graph =
+            UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 0], [2, 3], [3, 4], [4, 5], [5, 3]]);
+
+        assert_eq!(sorted_bridges(&graph), vec![(2, 3)]);
+        assert_eq!(sorted_articulation_points(&graph), vec![2, 3]);
+        assert_eq!(
+            sorted_components(&graph),
+            vec![vec![0, 1, 2], vec![2, 3], vec![3, 4, 5]]
+        );
+
+        let mut pieces: Vec<Vec<usize>> = graph
+            .split_at_bridges()
+            .iter()
+            .map(|piece| {
+                let mut vertices: Vec<usize> = piece.vertices.iter().copied().collect();
+                vertices.sort_unstable();
+                vertices
+            })
+            .collect();
+        pieces.sort();
+        assert_eq!(pieces, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    fn bowtie_shares_one_articulation_point() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 0], [2, 3], [3, 4], [4, 2]]);
+
+        assert!(sorted_bridges(&graph).is_empty());
+        assert_eq!(sorted_articulation_points(&graph), vec![2]);
+        assert_eq!(
+            sorted_components(&graph),
+            vec![vec![0, 1, 2], vec![2, 3, 4]]
+        );
+        assert_eq!(graph.split_at_bridges().len(), 1);
+    }
+
+    #[test]
+    fn isolated_vertices_belong_to_no_component() {
+        let mut graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 0]]);
+        graph.push_vertex(7);
+        graph.push_vertex(8);
+
+        assert!(sorted_bridges(&graph).is_empty());
+        assert!(sorted_articulation_points(&graph).is_empty());
+        assert_eq!(sorted_components(&graph), vec![vec![0, 1, 2]]);
+        assert_eq!(graph.split_at_bridges().len(), 3);
+    }
+}
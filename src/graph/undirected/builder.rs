@@ -0,0 +1,177 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use rayon::prelude::*;
+
+use super::UndirectedGraph;
+use crate::graph::interner::Interner;
+
+/*
+ *  Acumula arestas sem a verificação linear de duplicatas de `push_edge`, que custa O(grau)
+ *  por inserção. As duplicatas só são removidas em `build`, mantendo a ordem da primeira
+ *  ocorrência, de modo que o resultado é o mesmo de `UndirectedGraph::from`.
+ */
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<T> {
+    vertices: HashSet<T>,
+    adjacency: HashMap<T, Vec<T>>,
+}
+
+impl<T> Default for GraphBuilder<T> {
+    fn default() -> Self {
+        Self {
+            vertices: HashSet::new(),
+            adjacency: HashMap::new(),
+        }
+    }
+}
+
+impl<T> GraphBuilder<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_vertex(&mut self, vertex: T) {
+        self.vertices.insert(vertex);
+    }
+
+    pub fn push_edge(&mut self, from: T, to: T) {
+        self.push_arc(&from, to.clone());
+        self.push_arc(&to, from.clone());
+        self.push_vertex(from);
+        self.push_vertex(to);
+    }
+
+    // Evita clonar a chave quando o vértice já tem vizinhança
+    fn push_arc(&mut self, from: &T, to: T) {
+        match self.adjacency.get_mut(from) {
+            Some(neighbourhood) => neighbourhood.push(to),
+            None => {
+                self.adjacency.insert(from.clone(), vec![to]);
+            }
+        }
+    }
+
+    pub fn extend(&mut self, edges: impl IntoIterator<Item = [T; 2]>) {
+        for [from, to] in edges {
+            self.push_edge(from, to);
+        }
+    }
+
+    pub fn build(mut self) -> UndirectedGraph<T> {
+        self.adjacency
+            .par_iter_mut()
+            .for_each(|(_, neighbourhood)| {
+                let mut seen: HashSet<T> = HashSet::with_capacity(neighbourhood.len());
+                neighbourhood.retain(|neighbour| seen.insert(neighbour.clone()));
+            });
+
+        UndirectedGraph {
+            vertices: self.vertices,
+            adjacency: self.adjacency,
+        }
+    }
+}
+
+/*
+ *  Como `GraphBuilder`, mas troca os rótulos por identificadores à medida que chegam: o grafo
+ *  com rótulos nunca é montado, e cada rótulo é guardado uma única vez, no `Interner`.
+ */
+#[derive(Debug, Clone)]
+pub struct InternedBuilder<T> {
+    interner: Interner<T>,
+    builder: GraphBuilder<usize>,
+}
+
+impl<T> Default for InternedBuilder<T> {
+    fn default() -> Self {
+        Self {
+            interner: Interner::default(),
+            builder: GraphBuilder::default(),
+        }
+    }
+}
+
+impl<T> InternedBuilder<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_vertex(&mut self, vertex: T) {
+        let id = self.interner.intern(vertex);
+        self.builder.push_vertex(id);
+    }
+
+    pub fn push_edge(&mut self, from: T, to: T) {
+        let from = self.interner.intern(from);
+        let to = self.interner.intern(to);
+        self.builder.push_edge(from, to);
+    }
+
+    pub fn extend(&mut self, edges: impl IntoIterator<Item = [T; 2]>) {
+        for [from, to] in edges {
+            self.push_edge(from, to);
+        }
+    }
+
+    // Os identificadores seguem a ordem estável dos rótulos, como em `Interner::from_graph`
+    pub fn build(mut self) -> (Interner<T>, UndirectedGraph<usize>) {
+        let positions = self.interner.sort_stable();
+        let mut graph = self.builder.build();
+
+        graph.vertices = graph.vertices.iter().map(|id| positions[*id]).collect();
+        graph.adjacency = graph
+            .adjacency
+            .into_iter()
+            .map(|(id, mut neighbourhood)| {
+                neighbourhood
+                    .iter_mut()
+                    .for_each(|neighbour| *neighbour = positions[*neighbour]);
+                (positions[id], neighbourhood)
+            })
+            .collect();
+
+        (self.interner, graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interned_builder_matches_interning_the_built_graph() {
+        let edges: Vec<[String; 2]> = [
+            ["b", "a"],
+            ["ab", "b"],
+            ["c", "a"],
+            ["a", "b"],
+            ["c", "c"],
+            ["10", "9"],
+        ]
+        .map(|[from, to]| [from.to_string(), to.to_string()])
+        .to_vec();
+
+        let mut builder = InternedBuilder::new();
+        builder.extend(edges.clone());
+        builder.push_vertex(String::from("isolated"));
+        let (interner, graph) = builder.build();
+
+        let mut labelled = UndirectedGraph::from(edges);
+        labelled.push_vertex(String::from("isolated"));
+        let (expected_interner, expected) = Interner::from_graph(&labelled);
+
+        assert_eq!(interner.get_labels(), expected_interner.get_labels());
+        assert_eq!(graph.vertices, expected.vertices);
+        assert_eq!(graph.adjacency, expected.adjacency);
+    }
+}
@@ -0,0 +1,490 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use rayon::prelude::*;
+
+use super::{UndirectedGraph, indexed::IndexedGraph};
+
+const TOLERANCE: f64 = 1e-10;
+const MAX_ITERATIONS: usize = 1000;
+
+/*
+ *  Resultado de uma BFS de Brandes a partir de `source`: os vértices na ordem em que foram
+ *  alcançados, a distância e a quantidade de menores caminhos até cada um
+ */
+pub(crate) struct ShortestPathsDag {
+    pub order: Vec<usize>,
+    pub distance: Vec<usize>,
+    pub sigma: Vec<f64>,
+}
+
+impl<T> IndexedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone,
+{
+    pub(crate) fn shortest_paths_dag(&self, source: usize) -> ShortestPathsDag {
+        let mut distance = vec![usize::MAX; self.len()];
+        let mut sigma = vec![0.; self.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([source]);
+        distance[source] = 0;
+        sigma[source] = 1.;
+
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+            for &neighbour in &self.adjacency[vertex] {
+                if distance[neighbour] == usize::MAX {
+                    distance[neighbour] = distance[vertex] + 1;
+                    queue.push_back(neighbour);
+                }
+                if distance[neighbour] == distance[vertex] + 1 {
+                    sigma[neighbour] += sigma[vertex];
+                }
+            }
+        }
+
+        ShortestPathsDag {
+            order,
+            distance,
+            sigma,
+        }
+    }
+
+    /*
+     *  Acumulação de dependências de Brandes a partir de `source`. Retorna a dependência de
+     *  cada vértice e chama `on_edge(v, w, c)` com a contribuição `c` de cada aresta `v -> w`
+     *  do DAG de menores caminhos, o que serve tanto ao betweenness de vértices quanto ao de
+     *  arestas.
+     */
+    pub(crate) fn brandes_from(
+        &self,
+        source: usize,
+        mut on_edge: impl FnMut(usize, usize, f64),
+    ) -> Vec<f64> {
+        let dag = self.shortest_paths_dag(source);
+        let mut delta = vec![0.; self.len()];
+
+        for &vertex in dag.order.iter().rev() {
+            for &predecessor in &self.adjacency[vertex] {
+                if dag.distance[predecessor] != usize::MAX
+                    && dag.distance[predecessor] + 1 == dag.distance[vertex]
+                {
+                    let contribution =
+                        dag.sigma[predecessor] / dag.sigma[vertex] * (1. + delta[vertex]);
+                    delta[predecessor] += contribution;
+                    on_edge(predecessor, vertex, contribution);
+                }
+            }
+        }
+
+        delta
+    }
+
+    pub(crate) fn get_vertices_betweenness(&self) -> Vec<f64> {
+        let mut betweenness = (0..self.len())
+            .into_par_iter()
+            .fold(
+                || vec![0.; self.len()],
+                |mut acc, source| {
+                    let delta = self.brandes_from(source, |_, _, _| {});
+                    for (vertex, value) in delta.into_iter().enumerate() {
+                        if vertex != source {
+                            acc[vertex] += value;
+                        }
+                    }
+                    acc
+                },
+            )
+            .reduce(|| vec![0.; self.len()], sum_vectors);
+
+        // Cada par não ordenado foi contado a partir das duas pontas
+        betweenness.iter_mut().for_each(|value| *value /= 2.);
+        betweenness
+    }
+
+    // Soma das distâncias e quantidade de vértices alcançados a partir de cada vértice
+    fn get_distance_sums(&self) -> Vec<(usize, usize, f64)> {
+        (0..self.len())
+            .into_par_iter()
+            .map(|source| {
+                let dag = self.shortest_paths_dag(source);
+                let mut sum = 0;
+                let mut harmonic = 0.;
+                for &vertex in &dag.order[1..] {
+                    sum += dag.distance[vertex];
+                    harmonic += 1. / dag.distance[vertex] as f64;
+                }
+                (dag.order.len(), sum, harmonic)
+            })
+            .collect()
+    }
+
+    /*
+     *  Iteração de potência genérica: `step` recebe o vetor atual e o vértice e devolve o novo
+     *  valor; `normalize` é aplicado ao vetor após cada passo. Retorna `None` se não convergir.
+     */
+    fn power_iteration(
+        &self,
+        initial: Vec<f64>,
+        step: impl Fn(&[f64], usize) -> f64 + Sync,
+        normalize: impl Fn(&mut [f64]),
+    ) -> Option<Vec<f64>> {
+        let mut current = initial;
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut next: Vec<f64> = (0..self.len())
+                .into_par_iter()
+                .map(|vertex| step(&current, vertex))
+                .collect();
+            normalize(&mut next);
+
+            let change: f64 = next
+                .iter()
+                .zip(current.iter())
+                .map(|(x, y)| (x - y).abs())
+                .sum();
+            current = next;
+            if change < TOLERANCE * self.len() as f64 {
+                return Some(current);
+            }
+            // Divergiu (por exemplo, Katz com alpha grande demais)
+            if !change.is_finite() {
+                return None;
+            }
+        }
+
+        None
+    }
+}
+
+fn sum_vectors(mut x: Vec<f64>, y: Vec<f64>) -> Vec<f64> {
+    x.iter_mut().zip(y).for_each(|(x, y)| *x += y);
+    x
+}
+
+fn scale_to_max(values: &mut [f64]) {
+    let max = values.iter().cloned().fold(0., f64::max);
+    if max > 0. {
+        values.iter_mut().for_each(|value| *value /= max);
+    }
+}
+
+fn scale_to_unit_norm(values: &mut [f64]) {
+    let norm = values.iter().map(|value| value * value).sum::<f64>().sqrt();
+    if norm > 0. {
+        values.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+/*
+ *  Medidas de centralidade de vértices. Todas aceitam `normalized`:
+ *  - grau, harmônica e betweenness são divididos pelo máximo possível num grafo de n vértices
+ *  - closeness usa a correção de Wasserman–Faust para grafos desconexos
+ *  - autovetor, Katz e PageRank são escalados para que o maior valor seja 1
+ */
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn get_degree_centrality(&self, normalized: bool) -> HashMap<T, f64> {
+        let n = self.vertices.len() as f64;
+
+        self.vertices
+            .par_iter()
+            .map(|vertex| {
+                let degree = self.get_neighbourhood(vertex).map_or(0, Vec::len) as f64;
+                let value = if normalized && n > 1. {
+                    degree / (n - 1.)
+                } else {
+                    degree
+                };
+                (vertex.clone(), value)
+            })
+            .collect()
+    }
+
+    /*
+     *  Inverso da soma das distâncias até os vértices alcançáveis. Normalizada, vira
+     *  (r - 1)/soma multiplicado por (r - 1)/(n - 1), com r a quantidade de alcançados.
+     */
+    pub fn get_closeness_centrality(&self, normalized: bool) -> HashMap<T, f64> {
+        let indexed = self.to_indexed();
+        let n = indexed.len() as f64;
+
+        let values = indexed
+            .get_distance_sums()
+            .into_iter()
+            .map(|(reached, sum, _)| {
+                if sum == 0 {
+                    return 0.;
+                }
+                let reached = reached as f64;
+                if normalized {
+                    (reached - 1.) / sum as f64 * (reached - 1.) / (n - 1.)
+                } else {
+                    1. / sum as f64
+                }
+            })
+            .collect();
+
+        indexed.label(values)
+    }
+
+    // Soma dos inversos das distâncias, que lida naturalmente com grafos desconexos
+    pub fn get_harmonic_centrality(&self, normalized: bool) -> HashMap<T, f64> {
+        let indexed = self.to_indexed();
+        let n = indexed.len() as f64;
+
+        let values = indexed
+            .get_distance_sums()
+            .into_iter()
+            .map(|(_, _, harmonic)| {
+                if normalized && n > 1. {
+                    harmonic / (n - 1.)
+                } else {
+                    harmonic
+                }
+            })
+            .collect();
+
+        indexed.label(values)
+    }
+
+    pub fn get_vertices_betweenness(&self, normalized: bool) -> HashMap<T, f64> {
+        let indexed = self.to_indexed();
+        let n = indexed.len() as f64;
+        let mut values = indexed.get_vertices_betweenness();
+
+        if normalized && n > 2. {
+            let pairs = (n - 1.) * (n - 2.) / 2.;
+            values.iter_mut().for_each(|value| *value /= pairs);
+        }
+
+        indexed.label(values)
+    }
+
+    /*
+     *  Iteração de potência sobre A + I (o deslocamento evita a oscilação em grafos
+     *  bipartidos sem mudar os autovetores). Sem normalizar, o vetor tem norma euclidiana 1.
+     */
+    pub fn get_eigenvector_centrality(&self, normalized: bool) -> Result<HashMap<T, f64>, String> {
+        let indexed = self.to_indexed();
+        if indexed.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let initial = vec![1. / (indexed.len() as f64).sqrt(); indexed.len()];
+        let mut values = indexed
+            .power_iteration(
+                initial,
+                |current, vertex| {
+                    current[vertex]
+                        + indexed.adjacency[vertex]
+                            .iter()
+                            .map(|neighbour| current[*neighbour])
+                            .sum::<f64>()
+                },
+                scale_to_unit_norm,
+            )
+            .ok_or(format!(
+                "EIGENVECTOR CENTRALITY DID NOT CONVERGE IN {} ITERATIONS",
+                MAX_ITERATIONS
+            ))?;
+
+        if normalized {
+            scale_to_max(&mut values);
+        }
+        Ok(indexed.label(values))
+    }
+
+    /*
+     *  Ponto fixo de x = alpha * A x + beta. Só converge com alpha menor que o inverso do
+     *  maior autovalor de A.
+     */
+    pub fn get_katz_centrality(
+        &self,
+        alpha: f64,
+        beta: f64,
+        normalized: bool,
+    ) -> Result<HashMap<T, f64>, String> {
+        let indexed = self.to_indexed();
+
+        let mut values = indexed
+            .power_iteration(
+                vec![0.; indexed.len()],
+                |current, vertex| {
+                    alpha
+                        * indexed.adjacency[vertex]
+                            .iter()
+                            .map(|neighbour| current[*neighbour])
+                            .sum::<f64>()
+                        + beta
+                },
+                |_| {},
+            )
+            .ok_or(format!(
+                "KATZ CENTRALITY DID NOT CONVERGE IN {} ITERATIONS, ALPHA MUST BE SMALLER THAN 1/λmax",
+                MAX_ITERATIONS
+            ))?;
+
+        if normalized {
+            scale_to_max(&mut values);
+        }
+        Ok(indexed.label(values))
+    }
+
+    /*
+     *  PageRank com fator de amortecimento `damping`. O salto aleatório (e a massa dos vértices
+     *  isolados) segue `personalization`, ou a distribuição uniforme quando ela não é dada ou
+     *  não tem massa. Sem normalizar, os valores somam 1.
+     */
+    pub fn get_pagerank(
+        &self,
+        damping: f64,
+        personalization: Option<&HashMap<T, f64>>,
+        normalized: bool,
+    ) -> Result<HashMap<T, f64>, String> {
+        let indexed = self.to_indexed();
+        let n = indexed.len();
+        if n == 0 {
+            return Ok(HashMap::new());
+        }
+
+        let mut teleport: Vec<f64> = match personalization {
+            Some(weights) => indexed
+                .vertices
+                .iter()
+                .map(|vertex| weights.get(vertex).copied().unwrap_or(0.).max(0.))
+                .collect(),
+            None => vec![1.; n],
+        };
+        let total: f64 = teleport.iter().sum();
+        if total > 0. {
+            teleport.iter_mut().for_each(|value| *value /= total);
+        } else {
+            teleport = vec![1. / n as f64; n];
+        }
+
+        // A massa que não segue arestas (salto e vértices isolados) é redistribuída por `teleport`
+        let mut values = indexed
+            .power_iteration(
+                vec![1. / n as f64; n],
+                |current, vertex| {
+                    damping
+                        * indexed.adjacency[vertex]
+                            .iter()
+                            .map(|neighbour| {
+                                current[*neighbour] / indexed.degree(*neighbour) as f64
+                            })
+                            .sum::<f64>()
+                },
+                |next| {
+                    let missing = 1. - next.iter().sum::<f64>();
+                    next.iter_mut()
+                        .zip(teleport.iter())
+                        .for_each(|(value, teleport)| *value += missing * teleport);
+                },
+            )
+            .ok_or(format!(
+                "PAGERANK DID NOT CONVERGE IN {} ITERATIONS",
+                MAX_ITERATIONS
+            ))?;
+
+        if normalized {
+            scale_to_max(&mut values);
+        }
+        Ok(indexed.label(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(x: f64, y: f64) {
+        assert!((x - y).abs() < 1e-9, "{} != {}", x, y);
+    }
+
+    fn complete(n: usize) -> UndirectedGraph<usize> {
+        let mut edges = Vec::new();
+        for u in 0..n {
+            for v in u + 1..n {
+                edges.push([u, v]);
+            }
+        }
+        UndirectedGraph::from(edges)
+    }
+
+    #[test]
+    fn pagerank_is_a_distribution() {
+        let mut graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 0], [2, 3], [3, 4]]);
+        graph.push_vertex(5);
+        let pagerank = graph.get_pagerank(0.85, None, false).unwrap();
+
+        assert_close(pagerank.values().sum(), 1.);
+        assert!(pagerank[&2] > pagerank[&0]);
+        assert!(pagerank[&5] < pagerank[&0]);
+
+        let personalization = HashMap::from([(0, 1.)]);
+        let personalized = graph
+            .get_pagerank(0.85, Some(&personalization), false)
+            .unwrap();
+        assert_close(personalized.values().sum(), 1.);
+        assert!(personalized[&0] > pagerank[&0]);
+    }
+
+    #[test]
+    fn pagerank_of_a_cycle_is_uniform() {
+        let cycle = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3], [3, 0]]);
+
+        for value in cycle.get_pagerank(0.85, None, false).unwrap().values() {
+            assert_close(*value, 0.25);
+        }
+    }
+
+    #[test]
+    fn katz_fails_when_alpha_exceeds_the_inverse_eigenvalue() {
+        // O maior autovalor de K4 é 3
+        let graph = complete(4);
+
+        assert!(graph.get_katz_centrality(0.5, 1., false).is_err());
+        let katz = graph.get_katz_centrality(0.1, 1., false).unwrap();
+        // Em grafos regulares, x = beta / (1 - alpha * k)
+        for value in katz.values() {
+            assert_close(*value, 1. / 0.7);
+        }
+    }
+
+    #[test]
+    fn path_centralities() {
+        let path = UndirectedGraph::from(vec![[0, 1], [1, 2]]);
+
+        let betweenness = path.get_vertices_betweenness(true);
+        assert_close(betweenness[&1], 1.);
+        assert_close(betweenness[&0], 0.);
+
+        let closeness = path.get_closeness_centrality(true);
+        assert_close(closeness[&1], 1.);
+        assert_close(closeness[&0], 2. / 3.);
+
+        let harmonic = path.get_harmonic_centrality(false);
+        assert_close(harmonic[&0], 1.5);
+
+        let degree = path.get_degree_centrality(true);
+        assert_close(degree[&1], 1.);
+        assert_close(degree[&2], 0.5);
+    }
+
+    #[test]
+    fn eigenvector_centrality_peaks_at_the_hub() {
+        let star = UndirectedGraph::from(vec![[0, 1], [0, 2], [0, 3], [0, 4]]);
+        let eigenvector = star.get_eigenvector_centrality(true).unwrap();
+
+        assert_close(eigenvector[&0], 1.);
+        // Na estrela, cada folha vale 1/sqrt(k) do centro
+        assert_close(eigenvector[&1], 0.5);
+    }
+}
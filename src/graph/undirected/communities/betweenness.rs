@@ -1,10 +1,16 @@
 use crate::graph::{
     edge::Edge,
-    undirected::{Community, UndirectedGraph},
-    utils::Utils,
+    undirected::{
+        Community, UndirectedGraph,
+        approximate_betweenness::BetweennessSampling,
+        communities::{
+            context::RunContext, observer::IterationEvent, partition::Partition,
+            quality::QualityFunction,
+        },
+    },
 };
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     fmt::{Debug, Display},
     hash::Hash,
     time::Instant,
@@ -14,20 +20,57 @@ impl<T> UndirectedGraph<T>
 where
     T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
 {
-    pub fn betweenness(&self) -> HashMap<usize, Vec<HashSet<T>>> {
+    /*
+     *  Com `sampling`, cada iteração usa o betweenness aproximado (semente deslocada pelo número
+     *  da iteração) e recorre ao exato quando a amostra não cobre nenhuma aresta
+     */
+    pub fn betweenness(
+        &self,
+        quality: QualityFunction,
+        sampling: Option<BetweennessSampling>,
+        context: &RunContext,
+    ) -> Partition<T> {
         let mut graph = self.clone();
-        let mut generated_communities: HashMap<usize, Vec<Community<T>>> = HashMap::new();
+        let mut generated_communities: HashMap<usize, (Vec<Community<T>>, f64)> = HashMap::new();
 
+        let communities = graph.get_communities();
+        let modularity = quality.evaluate(self, communities.clone());
+        generated_communities.insert(communities.len(), (communities, modularity));
+
+        let mut random = context.get_random();
+        let start = Instant::now();
         let mut counter = 0;
+        let mut truncated = false;
         while graph.has_edges() {
+            if context.should_stop(counter, start.elapsed()) {
+                truncated = true;
+                break;
+            }
+
             let start_iter = Instant::now();
-            let edges_betweenness = self.get_edges_betweenness();
+            let edges_betweenness = match sampling {
+                Some(sampling) => {
+                    let approximate = graph.get_approximate_edges_betweenness(
+                        sampling.with_seed(sampling.get_seed().wrapping_add(counter as u64)),
+                    );
+                    if approximate.values.is_empty() {
+                        graph.get_edges_betweenness()
+                    } else {
+                        approximate
+                    }
+                }
+                None => graph.get_edges_betweenness(),
+            };
+            let max = match random.as_mut() {
+                Some(random) => edges_betweenness.get_stable_max(random),
+                None => edges_betweenness.get_max(),
+            };
 
-            if edges_betweenness.get_max().is_none() {
+            if max.is_none() {
                 break;
             }
 
-            let edge_with_biggest_betweenness = edges_betweenness.get_max().unwrap().0.clone();
+            let edge_with_biggest_betweenness = max.unwrap().0.clone();
 
             // Remover a Edge
             graph.remove_edge(&Edge {
@@ -37,26 +80,29 @@ where
 
             // Registra a divisão da comunidade
             let communities = graph.get_communities();
-            if !generated_communities.contains_key(&(communities.len())) {
-                Utils::persist_communities(
-                    communities.clone(),
-                    format!(
-                        "{}_{}",
-                        communities.len(),
-                        self.get_modularity(graph.get_communities())
-                    ),
-                );
-            }
-
+            let modularity = quality.evaluate(self, communities.clone());
+            let quantity_of_communities = communities.len();
             generated_communities
-                .entry(communities.len())
-                .or_insert(communities.clone());
+                .entry(quantity_of_communities)
+                .or_insert((communities, modularity));
 
-            println!("General Time {}: {:?}", counter, start_iter.elapsed());
-            println!();
+            context.observer.on_iteration(&IterationEvent {
+                algorithm: "betweenness",
+                iteration: counter,
+                communities: quantity_of_communities,
+                modularity,
+                iteration_elapsed: start_iter.elapsed(),
+                elapsed: start.elapsed(),
+            });
             counter += 1;
         }
 
-        generated_communities
+        let mut partition =
+            Partition::from_hierarchy(generated_communities.into_values().collect());
+        partition.truncated = truncated;
+        if context.seed.is_some() {
+            partition.normalize();
+        }
+        partition
     }
 }
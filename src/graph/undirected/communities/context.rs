@@ -0,0 +1,102 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use super::observer::{Observer, Silent};
+use crate::utils::random::Random;
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Budget {
+    pub max_iterations: Option<usize>,
+    pub time_limit: Option<Duration>,
+}
+
+impl Budget {
+    pub fn is_exhausted(&self, iterations: usize, elapsed: Duration) -> bool {
+        self.max_iterations.is_some_and(|max| iterations >= max)
+            || self.time_limit.is_some_and(|limit| elapsed >= limit)
+    }
+
+    // O que sobra do orçamento depois de `iterations` iterações e `elapsed` de execução
+    pub fn get_remaining(&self, iterations: usize, elapsed: Duration) -> Self {
+        Self {
+            max_iterations: self
+                .max_iterations
+                .map(|max| max.saturating_sub(iterations)),
+            time_limit: self.time_limit.map(|limit| limit.saturating_sub(elapsed)),
+        }
+    }
+}
+
+pub struct RunContext<'a> {
+    pub observer: &'a dyn Observer,
+    pub budget: Budget,
+    pub cancellation: CancellationToken,
+    // Quando definida, os algoritmos rodam em modo determinístico
+    pub seed: Option<u64>,
+}
+
+impl<'a> RunContext<'a> {
+    pub fn new(observer: &'a dyn Observer) -> Self {
+        Self {
+            observer,
+            budget: Budget::default(),
+            cancellation: CancellationToken::default(),
+            seed: None,
+        }
+    }
+
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn get_random(&self) -> Option<Random> {
+        self.seed.map(Random::new)
+    }
+
+    /*
+     *  Verificado pelos algoritmos no início de cada iteração. Quando verdadeiro, o algoritmo
+     *  deve parar e devolver a melhor partição encontrada até então, marcada como truncada.
+     */
+    pub fn should_stop(&self, iterations: usize, elapsed: Duration) -> bool {
+        self.cancellation.is_cancelled() || self.budget.is_exhausted(iterations, elapsed)
+    }
+}
+
+impl Default for RunContext<'_> {
+    fn default() -> Self {
+        Self::new(&Silent)
+    }
+}
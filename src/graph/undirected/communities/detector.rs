@@ -0,0 +1,293 @@
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display},
+    hash::Hash,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use super::{
+    context::RunContext,
+    observer::{IterationEvent, Observer},
+    partition::Partition,
+    quality::QualityFunction,
+};
+use crate::graph::undirected::{UndirectedGraph, approximate_betweenness::BetweennessSampling};
+
+pub trait CommunityDetector<T> {
+    fn name(&self) -> &str;
+
+    // Função usada para escolher e avaliar a partição retornada
+    fn quality(&self) -> QualityFunction {
+        QualityFunction::default()
+    }
+
+    fn detect_with(&self, graph: &UndirectedGraph<T>, context: &RunContext) -> Partition<T>;
+
+    fn detect(&self, graph: &UndirectedGraph<T>) -> Partition<T> {
+        self.detect_with(graph, &RunContext::default())
+    }
+}
+
+/*
+ *  Remove arestas de maior betweenness e escolhe o nível de maior `quality`. Com `sampling`,
+ *  usa o betweenness aproximado (modo rápido).
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GirvanNewman {
+    pub quality: QualityFunction,
+    pub sampling: Option<BetweennessSampling>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HierarchicalGrowth {
+    pub alpha: f64,
+}
+
+impl Default for HierarchicalGrowth {
+    fn default() -> Self {
+        Self { alpha: 1. }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NewmanModularity {
+    pub quality: QualityFunction,
+}
+
+/*
+ *  Corta o grafo nas pontes e roda `inner` em cada pedaço separadamente. As comunidades dos
+ *  pedaços são unidas e avaliadas no grafo inteiro pela qualidade de `inner`. O orçamento de
+ *  tempo e de iterações vale para a execução toda, não para cada pedaço.
+ */
+pub struct SplitAtBridges<T> {
+    pub inner: Box<dyn CommunityDetector<T>>,
+    name: String,
+}
+
+impl<T> SplitAtBridges<T> {
+    pub fn new(inner: Box<dyn CommunityDetector<T>>) -> Self {
+        let name = format!("{}+bridges", inner.name());
+        Self { inner, name }
+    }
+}
+
+// Repassa os eventos e conta as iterações gastas pelos pedaços já processados
+struct IterationCounter<'a> {
+    inner: &'a dyn Observer,
+    iterations: AtomicUsize,
+}
+
+impl Observer for IterationCounter<'_> {
+    fn on_iteration(&self, event: &IterationEvent) {
+        self.iterations.fetch_add(1, Ordering::Relaxed);
+        self.inner.on_iteration(event);
+    }
+}
+
+impl<T> CommunityDetector<T> for GirvanNewman
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    fn name(&self) -> &str {
+        "betweenness"
+    }
+
+    fn quality(&self) -> QualityFunction {
+        self.quality
+    }
+
+    fn detect_with(&self, graph: &UndirectedGraph<T>, context: &RunContext) -> Partition<T> {
+        graph.betweenness(self.quality, self.sampling, context)
+    }
+}
+
+impl<T> CommunityDetector<T> for HierarchicalGrowth
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    fn name(&self) -> &str {
+        "hierarchical_growth"
+    }
+
+    fn detect_with(&self, graph: &UndirectedGraph<T>, context: &RunContext) -> Partition<T> {
+        graph.hierarchical_growth(self.alpha, context)
+    }
+}
+
+impl<T> CommunityDetector<T> for NewmanModularity
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    fn name(&self) -> &str {
+        "newmans_modularity_clustering"
+    }
+
+    fn quality(&self) -> QualityFunction {
+        self.quality
+    }
+
+    fn detect_with(&self, graph: &UndirectedGraph<T>, context: &RunContext) -> Partition<T> {
+        graph.newmans_modularity_clustering(self.quality, context)
+    }
+}
+
+impl<T> CommunityDetector<T> for SplitAtBridges<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn quality(&self) -> QualityFunction {
+        self.inner.quality()
+    }
+
+    fn detect_with(&self, graph: &UndirectedGraph<T>, context: &RunContext) -> Partition<T> {
+        let start = Instant::now();
+        let counter = IterationCounter {
+            inner: context.observer,
+            iterations: AtomicUsize::new(0),
+        };
+        let mut communities = Vec::new();
+        let mut truncated = false;
+
+        for piece in graph.split_at_bridges() {
+            if !piece.has_edges() {
+                communities.push(piece.vertices.iter().cloned().collect::<HashSet<T>>());
+                continue;
+            }
+
+            // Sem orçamento restante, o pedaço fica como uma comunidade só
+            let iterations = counter.iterations.load(Ordering::Relaxed);
+            let elapsed = start.elapsed();
+            if context.should_stop(iterations, elapsed) {
+                truncated = true;
+                communities.push(piece.vertices.iter().cloned().collect::<HashSet<T>>());
+                continue;
+            }
+
+            let piece_context = RunContext {
+                observer: &counter,
+                budget: context.budget.get_remaining(iterations, elapsed),
+                cancellation: context.cancellation.clone(),
+                seed: context.seed,
+            };
+            let partition = self.inner.detect_with(&piece, &piece_context);
+            truncated |= partition.truncated;
+            communities.extend(partition.communities);
+        }
+
+        let quality = self.quality().evaluate(graph, communities.clone());
+        let mut partition = Partition::with_quality(communities, quality);
+        partition.truncated = truncated;
+        if context.seed.is_some() {
+            partition.normalize();
+        }
+        partition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::Generators;
+
+    fn detectors() -> Vec<Box<dyn CommunityDetector<usize>>> {
+        vec![
+            Box::new(GirvanNewman::default()),
+            Box::new(GirvanNewman {
+                sampling: Some(BetweennessSampling::Pivots {
+                    samples: 8,
+                    seed: 0,
+                }),
+                ..Default::default()
+            }),
+            Box::new(HierarchicalGrowth::default()),
+            Box::new(NewmanModularity::default()),
+            Box::new(SplitAtBridges::new(Box::new(NewmanModularity::default()))),
+        ]
+    }
+
+    // Comunidades ordenadas, independentes da ordem de iteração dos `HashSet`
+    fn normalized(partition: &Partition<usize>) -> Vec<Vec<usize>> {
+        let mut communities: Vec<Vec<usize>> = partition
+            .communities
+            .iter()
+            .map(|community| {
+                let mut community: Vec<usize> = community.iter().copied().collect();
+                community.sort_unstable();
+                community
+            })
+            .collect();
+        communities.sort();
+        communities
+    }
+
+    #[test]
+    fn seeded_runs_are_reproducible() {
+        for detector in detectors() {
+            for seed in [1, 7] {
+                // Cada grafo tem sua própria semente de hash, então a ordem dos `HashMap` muda
+                let runs: Vec<Vec<Vec<usize>>> = (0..3)
+                    .map(|_| {
+                        let graph = Generators::relaxed_caveman(4, 6, 0.2, 3).graph;
+                        let context = RunContext::default().with_seed(seed);
+                        normalized(&detector.detect_with(&graph, &context))
+                    })
+                    .collect();
+
+                assert!(
+                    runs.windows(2).all(|pair| pair[0] == pair[1]),
+                    "{} with seed {} is not deterministic",
+                    detector.name(),
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_bridges_keeps_the_pieces_apart() {
+        // Dois K5 ligados pela ponte 4-5, mais o vértice isolado 10
+        let mut edges = vec![[4, 5]];
+        for start in [0, 5] {
+            for u in start..start + 5 {
+                for v in u + 1..start + 5 {
+                    edges.push([u, v]);
+                }
+            }
+        }
+        let mut graph = UndirectedGraph::from(edges);
+        graph.push_vertex(10);
+
+        let detector = SplitAtBridges::new(Box::new(NewmanModularity::default()));
+        let partition = detector.detect(&graph);
+
+        assert_eq!(detector.name(), "newmans_modularity_clustering+bridges");
+        assert_eq!(
+            normalized(&partition),
+            vec![vec![0, 1, 2, 3, 4], vec![5, 6, 7, 8, 9], vec![10]]
+        );
+        assert!(!partition.truncated);
+    }
+
+    #[test]
+    fn split_at_bridges_reports_the_inner_quality() {
+        let graph = Generators::relaxed_caveman(3, 5, 0.1, 2).graph;
+        let quality = QualityFunction::ConstantPotts { resolution: 0.2 };
+        let detector = SplitAtBridges::new(Box::new(NewmanModularity { quality }));
+        let partition = detector.detect(&graph);
+
+        assert_eq!(detector.quality(), quality);
+        assert_eq!(
+            partition.quality,
+            quality.evaluate(&graph, partition.communities.clone())
+        );
+        assert_ne!(
+            partition.quality,
+            graph.get_modularity(partition.communities)
+        );
+    }
+}
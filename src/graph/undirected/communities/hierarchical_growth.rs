@@ -5,41 +5,75 @@ use std::{
     time::Instant,
 };
 
-use crate::graph::{edge::Edge, undirected::UndirectedGraph, utils::Utils};
+use crate::graph::{
+    edge::Edge,
+    undirected::{
+        UndirectedGraph,
+        communities::{context::RunContext, observer::IterationEvent, partition::Partition},
+    },
+    utils::Utils,
+};
 
 impl<T> UndirectedGraph<T>
 where
     T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
 {
-    pub fn hierarchical_growth(&self) {
+    pub fn hierarchical_growth(&self, alfa: f64, context: &RunContext) -> Partition<T> {
         let vertices: Vec<&T> = self.vertices.iter().collect();
         let mut graph = self.clone();
+        // Acompanha as remoções de arestas de `graph`, sem reordenar os vértices a cada iteração
+        let mut indexed = graph.to_indexed();
+        let start = Instant::now();
+        let mut random = context.get_random();
+        let mut truncated = false;
 
         for (i, _) in vertices.iter().enumerate() {
+            if context.should_stop(i, start.elapsed()) {
+                truncated = true;
+                break;
+            }
+
             let time_main_loop = Instant::now();
-            let vertex_with_highest_clustering_coefficient =
-                graph.get_highest_clustering_coefficients().0;
+            let vertex_with_highest_clustering_coefficient = match random.as_mut() {
+                Some(random) => {
+                    let coefficients = graph.get_clustering_coefficients(&indexed);
+                    let highest = coefficients
+                        .iter()
+                        .map(|coefficient| coefficient.1)
+                        .fold(f64::MIN, f64::max);
+                    let ties = coefficients
+                        .into_iter()
+                        .filter(|coefficient| coefficient.1 == highest)
+                        .map(|coefficient| coefficient.0)
+                        .collect();
+                    Utils::stable_order(ties, Some(random))[0]
+                }
+                None => graph.get_highest_clustering_coefficients(&indexed).0,
+            };
             let mut community = vec![vertex_with_highest_clustering_coefficient.clone()];
             let mut has_grown = true;
 
             while has_grown {
                 has_grown = false;
 
-                let zero_block = Instant::now();
                 let first_neighbourhood = graph
                     .get_neighbourhood_from_community(&community.clone().into_iter().collect());
-                // println!("zero_block: {:?}", zero_block.elapsed());
 
                 // Talvez dê para paralelizar esta parte
-                for neighbour in first_neighbourhood.clone() {
-                    let first_block = Instant::now();
+                let mut candidates: Vec<T> = first_neighbourhood.iter().cloned().collect();
+                if let Some(random) = random.as_mut() {
+                    candidates = Utils::stable_order(candidates, Some(random));
+                }
+
+                for neighbour in candidates {
                     let community_set: HashSet<T> = community.iter().cloned().collect();
+                    if community_set.contains(&neighbour) {
+                        continue;
+                    }
+
                     let union_set: HashSet<T> =
                         community_set.union(&first_neighbourhood).cloned().collect();
                     if let Some(neighbourhood_of_neighbour) = graph.get_neighbourhood(&neighbour) {
-                        // println!("first_block: {:?}", first_block.elapsed());
-
-                        let second_block = Instant::now();
                         /*
                          *  Number of links of the vertex i with vertices belonging to community and
                          *  with vertices in the first neighborhood
@@ -65,13 +99,9 @@ where
                         if kin1 >= kout1 {
                             community.push(neighbour.clone());
                             has_grown = true;
-                            // println!("second_block: {:?}", second_block.elapsed());
                             continue;
                         }
 
-                        // println!("second_block: {:?}", second_block.elapsed());
-
-                        let third_block = Instant::now();
                         let second_neighborhood = graph.get_neighbourhood_from_community(
                             &union_set.clone().iter().cloned().collect(),
                         );
@@ -91,17 +121,14 @@ where
                             }
                         }
 
-                        let alfa = 1.;
-
                         if kin2 as f64 > alfa * kout2 as f64 {
                             community.push(neighbour.clone());
                             has_grown = true;
-                            println!("third_block: {:?}", third_block.elapsed());
                             continue;
                         }
 
                         if let Some(neighbourhood_of_neighbour) =
-                            graph.clone().get_neighbourhood(&neighbour).clone()
+                            graph.clone().get_neighbourhood(&neighbour)
                         {
                             for neighbour_of_neighbour in neighbourhood_of_neighbour {
                                 if community.contains(neighbour_of_neighbour) {
@@ -109,6 +136,10 @@ where
                                         from: neighbour.clone(),
                                         to: neighbour_of_neighbour.clone(),
                                     });
+                                    indexed.remove_edge(
+                                        indexed.index[&neighbour],
+                                        indexed.index[neighbour_of_neighbour],
+                                    );
                                 }
                             }
                         }
@@ -116,17 +147,22 @@ where
                 }
             }
 
-            println!("Time({}): {:?}", i, time_main_loop.elapsed());
+            let communities = graph.get_communities();
+            context.observer.on_iteration(&IterationEvent {
+                algorithm: "hierarchical_growth",
+                iteration: i,
+                communities: communities.len(),
+                modularity: self.get_modularity(communities),
+                iteration_elapsed: time_main_loop.elapsed(),
+                elapsed: start.elapsed(),
+            });
         }
 
-        let result = graph.get_communities();
-        Utils::persist_communities(
-            result.clone(),
-            format!(
-                "hierarchical/{}_{}",
-                result.len(),
-                dbg!(self.get_modularity(graph.get_communities()))
-            ),
-        );
+        let mut partition = Partition::new(self, graph.get_communities());
+        partition.truncated = truncated;
+        if context.seed.is_some() {
+            partition.normalize();
+        }
+        partition
     }
 }
@@ -1,9 +1,14 @@
 pub mod betweenness;
+pub mod context;
+pub mod detector;
 pub mod hierarchical_growth;
 pub mod newmans_modularity_clustering;
+pub mod observer;
+pub mod partition;
+pub mod quality;
+pub mod quotient;
 
 use std::{
-    collections::HashSet,
     fmt::{Debug, Display},
     hash::Hash,
 };
@@ -15,46 +20,11 @@ where
     T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
 {
     pub fn get_quantity_of_communities(&self) -> usize {
-        self.get_communities().len()
+        self.get_union_find().get_quantity_of_components()
     }
 
+    // Componentes conexas do grafo, via `get_connected_components`
     pub fn get_communities(&self) -> Vec<Community<T>> {
-        let mut visited = HashSet::new();
-        let mut communities = Vec::new();
-
-        for vertex in &self.vertices {
-            if visited.contains(vertex) {
-                continue;
-            }
-
-            let mut stack = vec![vertex.clone()];
-            let mut community = HashSet::new();
-
-            while let Some(current) = stack.pop() {
-                if !visited.insert(current.clone()) {
-                    continue;
-                }
-
-                community.insert(current.clone());
-
-                if let Some(neighbors) = self.adjacency.get(&current) {
-                    for neighbor in neighbors {
-                        if !visited.contains(neighbor) {
-                            stack.push(neighbor.clone());
-                        }
-                    }
-                }
-
-                for (other, neighbors) in &self.adjacency {
-                    if neighbors.contains(&current) && !visited.contains(other) {
-                        stack.push(other.clone());
-                    }
-                }
-            }
-
-            communities.push(community);
-        }
-
-        communities
+        self.get_connected_components()
     }
 }
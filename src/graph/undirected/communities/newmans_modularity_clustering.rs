@@ -7,7 +7,13 @@ use std::{
 
 use crate::{
     graph::{
-        undirected::{Community, UndirectedGraph},
+        undirected::{
+            Community, UndirectedGraph,
+            communities::{
+                context::RunContext, observer::IterationEvent, partition::Partition,
+                quality::QualityFunction,
+            },
+        },
         utils::Utils,
     },
     utils::OrderedF64,
@@ -17,8 +23,24 @@ impl<T> UndirectedGraph<T>
 where
     T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
 {
-    pub fn newmans_modularity_clustering(&self) -> HashMap<usize, Vec<HashSet<T>>> {
+    pub fn newmans_modularity_clustering(
+        &self,
+        quality: QualityFunction,
+        context: &RunContext,
+    ) -> Partition<T> {
+        let start_run = Instant::now();
         let total_of_edges = self.get_total_of_edges() as f64;
+
+        // Sem arestas todo ΔQ seria NaN; cada vértice fica sozinho
+        if total_of_edges == 0. {
+            let singletons = self
+                .get_ordered_vertices(None)
+                .into_iter()
+                .map(|vertex| Community::from([vertex]))
+                .collect();
+            return Partition::with_quality(singletons, 0.);
+        }
+
         let mut partitions: HashMap<usize, (Vec<Community<T>>, f64)> = HashMap::new();
         let mut communities: HashMap<usize, HashSet<T>> = HashMap::new();
         let mut delta_q: HashMap<(usize, usize), f64> = HashMap::new();
@@ -28,7 +50,19 @@ where
         let mut active_ids: HashSet<usize> = HashSet::new();
         let mut vertex_to_community: HashMap<&T, usize> = HashMap::new();
 
-        for (vertex, neighbourhood) in &self.adjacency {
+        let mut random = context.get_random();
+        // Vértices isolados entram como comunidades unitárias, com grau 0
+        let mut ordered_vertices: Vec<&T> = self.vertices.iter().collect();
+        if let Some(random) = random.as_mut() {
+            ordered_vertices = Utils::stable_order(ordered_vertices, Some(random));
+        }
+
+        let no_neighbours: Vec<T> = Vec::new();
+        let get_neighbourhood =
+            |vertex: &T| self.get_neighbourhood(vertex).unwrap_or(&no_neighbours);
+
+        for vertex in ordered_vertices.iter().copied() {
+            let neighbourhood = get_neighbourhood(vertex);
             communities.insert(community_id, HashSet::from([vertex.clone()]));
             degrees.insert(community_id, neighbourhood.len() as f64);
             active_ids.insert(community_id);
@@ -36,7 +70,8 @@ where
             community_id += 1;
         }
 
-        for (vertex, neighbourhood) in &self.adjacency {
+        for vertex in ordered_vertices.iter().copied() {
+            let neighbourhood = get_neighbourhood(vertex);
             let community_i = vertex_to_community[vertex];
             for neighbour in neighbourhood {
                 let community_j = vertex_to_community[neighbour];
@@ -45,15 +80,17 @@ where
                 } else {
                     (community_j, community_i)
                 };
+                let neighbour_degree = self.get_neighbourhood(neighbour).map_or(0, Vec::len);
                 delta_q
                     .entry(key)
                     .and_modify(|value| *value += 1. / total_of_edges)
                     .or_insert(
                         (1. / total_of_edges)
-                            - ((2.
-                                * neighbourhood.len() as f64
-                                * self.get_neighbourhood(neighbour).iter().len() as f64)
-                                / total_of_edges.powf(2.)),
+                            - quality.get_merge_penalty(
+                                (neighbourhood.len() as f64, neighbour_degree as f64),
+                                (1., 1.),
+                                total_of_edges,
+                            ),
                     );
             }
         }
@@ -62,66 +99,137 @@ where
             heap.push((OrderedF64(*modularity), *community_i, *community_j));
         }
 
+        let singletons = (0..community_id)
+            .map(|id| communities[&id].clone())
+            .collect::<Vec<_>>();
+        let q = quality.evaluate(self, singletons.clone());
+        partitions.insert(active_ids.len(), (singletons, q));
+
+        let mut iteration = 0;
+        let mut truncated = false;
         while active_ids.len() > 1 {
-            let highest = heap.pop();
-
-            if let Some(highest) = highest {
-                let start = Instant::now();
-
-                if !active_ids.contains(&highest.1) || !active_ids.contains(&highest.2) {
-                    continue;
-                }
-
-                let community_i = communities.get(&highest.1).unwrap();
-                let community_j = communities.get(&highest.2).unwrap();
-                let degree_community_i = degrees.get(&highest.1).unwrap();
-                let degree_community_j = degrees.get(&highest.2).unwrap();
-                let new_degree = *degree_community_i + *degree_community_j;
-                let unified_communities: HashSet<T> =
-                    community_i.union(community_j).cloned().collect();
-
-                active_ids.remove(&highest.1);
-                active_ids.remove(&highest.2);
-
-                for active_id in &active_ids {
-                    let e_ij = self
-                        .get_neighbourhood_from_community(&unified_communities)
-                        .intersection(communities.get(active_id).unwrap())
-                        .collect::<HashSet<_>>()
-                        .len() as f64
-                        / total_of_edges;
-                    let degree = *degrees.get(active_id).unwrap();
-                    let new_delta_q =
-                        2. * (e_ij - ((new_degree * degree) / (total_of_edges.powf(2.))));
-
-                    heap.push((OrderedF64(new_delta_q), *active_id, community_id));
-                }
-
-                degrees.insert(community_id, new_degree);
-                communities.insert(community_id, unified_communities);
-                active_ids.insert(community_id);
-                community_id += 1;
-
-                let snapshot = active_ids
-                    .iter()
-                    .map(|id| communities[id].clone())
-                    .collect::<Vec<_>>();
-                let q = self.get_modularity(snapshot.clone());
-                partitions.insert(self.vertices.len() - partitions.len(), (snapshot, q));
-                println!("Time({}): {:?}", active_ids.len(), start.elapsed());
+            if context.should_stop(iteration, start_run.elapsed()) {
+                truncated = true;
+                break;
+            }
+
+            // Sem pares restantes não há mais fusões possíveis
+            let Some(highest) = heap.pop() else {
+                break;
+            };
+
+            let start = Instant::now();
+
+            if !active_ids.contains(&highest.1) || !active_ids.contains(&highest.2) {
+                continue;
+            }
+
+            let community_i = communities.get(&highest.1).unwrap();
+            let community_j = communities.get(&highest.2).unwrap();
+            let degree_community_i = degrees.get(&highest.1).unwrap();
+            let degree_community_j = degrees.get(&highest.2).unwrap();
+            let new_degree = *degree_community_i + *degree_community_j;
+            let unified_communities: HashSet<T> = community_i.union(community_j).cloned().collect();
+
+            active_ids.remove(&highest.1);
+            active_ids.remove(&highest.2);
+
+            for active_id in &active_ids {
+                let e_ij = self
+                    .get_neighbourhood_from_community(&unified_communities)
+                    .intersection(communities.get(active_id).unwrap())
+                    .collect::<HashSet<_>>()
+                    .len() as f64
+                    / total_of_edges;
+                let degree = *degrees.get(active_id).unwrap();
+                let size = communities[active_id].len() as f64;
+                let new_delta_q = 2. * e_ij
+                    - quality.get_merge_penalty(
+                        (new_degree, degree),
+                        (unified_communities.len() as f64, size),
+                        total_of_edges,
+                    );
+
+                heap.push((OrderedF64(new_delta_q), *active_id, community_id));
             }
+
+            degrees.insert(community_id, new_degree);
+            communities.insert(community_id, unified_communities);
+            active_ids.insert(community_id);
+            community_id += 1;
+
+            let mut snapshot_ids = active_ids.iter().copied().collect::<Vec<_>>();
+            snapshot_ids.sort_unstable();
+            let snapshot = snapshot_ids
+                .iter()
+                .map(|id| communities[id].clone())
+                .collect::<Vec<_>>();
+            let q = quality.evaluate(self, snapshot.clone());
+            partitions.insert(active_ids.len(), (snapshot, q));
+            iteration += 1;
+            context.observer.on_iteration(&IterationEvent {
+                algorithm: "newmans_modularity_clustering",
+                iteration,
+                communities: active_ids.len(),
+                modularity: q,
+                iteration_elapsed: start.elapsed(),
+                elapsed: start_run.elapsed(),
+            });
         }
 
-        for communities in partitions.values() {
-            Utils::persist_communities(
-                communities.0.clone(),
-                format!("{}_{}", communities.0.len(), communities.1),
-            );
+        let mut partition = Partition::from_hierarchy(partitions.into_values().collect());
+        partition.truncated = truncated;
+        if context.seed.is_some() {
+            partition.normalize();
         }
+        partition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        partitions
-            .iter()
-            .map(|(key, value)| (*key, value.0.clone()))
-            .collect::<HashMap<_, _>>()
+    #[test]
+    fn edgeless_graph_gives_singletons() {
+        let mut graph: UndirectedGraph<usize> = UndirectedGraph::new();
+        for vertex in 0..4 {
+            graph.push_vertex(vertex);
+        }
+
+        let partition =
+            graph.newmans_modularity_clustering(QualityFunction::default(), &RunContext::default());
+
+        assert_eq!(partition.communities.len(), 4);
+        assert!(
+            partition
+                .communities
+                .iter()
+                .all(|community| community.len() == 1)
+        );
+        assert_eq!(partition.quality, 0.);
+    }
+
+    #[test]
+    fn isolated_vertices_and_disconnected_pieces_terminate() {
+        // Dois triângulos sem ligação entre si e dois vértices isolados
+        let mut graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 0], [3, 4], [4, 5], [5, 3]]);
+        graph.push_vertex(6);
+        graph.push_vertex(7);
+
+        let partition =
+            graph.newmans_modularity_clustering(QualityFunction::default(), &RunContext::default());
+
+        assert!(partition.quality.is_finite());
+        let assigned: HashSet<usize> = partition.communities.iter().flatten().copied().collect();
+        assert_eq!(assigned, graph.vertices);
+        for triangle in [[0, 1, 2], [3, 4, 5]] {
+            assert!(
+                partition
+                    .communities
+                    .iter()
+                    .any(|community| triangle.iter().all(|vertex| community.contains(vertex)))
+            );
+        }
     }
 }
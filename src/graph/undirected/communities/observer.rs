@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct IterationEvent<'a> {
+    pub algorithm: &'a str,
+    pub iteration: usize,
+    pub communities: usize,
+    pub modularity: f64,
+    pub iteration_elapsed: Duration,
+    pub elapsed: Duration,
+}
+
+pub trait Observer {
+    fn on_iteration(&self, event: &IterationEvent);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Logger;
+
+impl Observer for Logger {
+    fn on_iteration(&self, event: &IterationEvent) {
+        eprintln!(
+            "{}({}): {} communities, Q = {:.6}, {:?} (total {:?})",
+            event.algorithm,
+            event.iteration,
+            event.communities,
+            event.modularity,
+            event.iteration_elapsed,
+            event.elapsed
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Silent;
+
+impl Observer for Silent {
+    fn on_iteration(&self, _event: &IterationEvent) {}
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::graph::{
+    undirected::{Community, UndirectedGraph},
+    utils::Utils,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Partition<T> {
+    // `membership[i]` é o índice em `communities` da comunidade de `vertices[i]`
+    pub vertices: Vec<T>,
+    pub membership: Vec<usize>,
+    pub communities: Vec<Community<T>>,
+    pub quality: f64,
+    // Níveis intermediários ordenados pela quantidade de comunidades
+    pub hierarchy: Option<Vec<Vec<Community<T>>>>,
+    // Verdadeiro quando a execução foi interrompida por cancelamento ou orçamento
+    pub truncated: bool,
+}
+
+impl<T> Partition<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn new(graph: &UndirectedGraph<T>, communities: Vec<Community<T>>) -> Self {
+        let quality = graph.get_modularity(communities.clone());
+        Self::with_quality(communities, quality)
+    }
+
+    pub fn with_quality(communities: Vec<Community<T>>, quality: f64) -> Self {
+        let mut vertices = Vec::new();
+        let mut membership = Vec::new();
+
+        for (i, community) in communities.iter().enumerate() {
+            for vertex in community {
+                vertices.push(vertex.clone());
+                membership.push(i);
+            }
+        }
+
+        Self {
+            vertices,
+            membership,
+            communities,
+            quality,
+            hierarchy: None,
+            truncated: false,
+        }
+    }
+
+    /*
+     *  Escolhe o nível de maior qualidade como partição final e mantém todos os níveis na
+     *  hierarquia
+     */
+    pub fn from_hierarchy(mut levels: Vec<(Vec<Community<T>>, f64)>) -> Self {
+        levels.sort_by_key(|level| level.0.len());
+
+        let best = levels
+            .iter()
+            .max_by(|x, y| x.1.total_cmp(&y.1))
+            .cloned()
+            .unwrap_or_default();
+
+        let mut partition = Self::with_quality(best.0, best.1);
+        partition.hierarchy = Some(levels.into_iter().map(|level| level.0).collect());
+        partition
+    }
+
+    /*
+     *  Ordena as comunidades (e os níveis da hierarquia) pelo menor vértice de cada uma, para
+     *  que execuções determinísticas produzam saídas idênticas
+     */
+    pub fn normalize(&mut self) {
+        Self::sort_communities(&mut self.communities);
+        if let Some(hierarchy) = self.hierarchy.as_mut() {
+            hierarchy
+                .iter_mut()
+                .for_each(|level| Self::sort_communities(level));
+        }
+
+        let mut vertices = Vec::new();
+        let mut membership = Vec::new();
+        for (i, community) in self.communities.iter().enumerate() {
+            for vertex in Utils::stable_order(community.iter().collect(), None) {
+                vertices.push(vertex.clone());
+                membership.push(i);
+            }
+        }
+
+        self.vertices = vertices;
+        self.membership = membership;
+    }
+
+    fn sort_communities(communities: &mut [Community<T>]) {
+        communities.sort_by_cached_key(|community| community.iter().map(Utils::stable_key).min());
+    }
+
+    pub fn len(&self) -> usize {
+        self.communities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.communities.is_empty()
+    }
+
+    pub fn get_community_of(&self, vertex: &T) -> Option<usize> {
+        self.communities
+            .iter()
+            .position(|community| community.contains(vertex))
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::graph::undirected::{Community, UndirectedGraph};
+
+// Função objetivo dos algoritmos que otimizam a qualidade da partição
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QualityFunction {
+    Modularity { resolution: f64 },
+    ConstantPotts { resolution: f64 },
+}
+
+impl Default for QualityFunction {
+    fn default() -> Self {
+        Self::Modularity { resolution: 1. }
+    }
+}
+
+impl Display for QualityFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Modularity { resolution } if *resolution == 1. => write!(f, "Modularity"),
+            Self::Modularity { resolution } => write!(f, "Modularity (resolution {})", resolution),
+            Self::ConstantPotts { resolution } => write!(f, "CPM (resolution {})", resolution),
+        }
+    }
+}
+
+impl QualityFunction {
+    pub fn get_resolution(&self) -> f64 {
+        match self {
+            Self::Modularity { resolution } | Self::ConstantPotts { resolution } => *resolution,
+        }
+    }
+
+    pub fn with_resolution(&self, resolution: f64) -> Self {
+        match self {
+            Self::Modularity { .. } => Self::Modularity { resolution },
+            Self::ConstantPotts { .. } => Self::ConstantPotts { resolution },
+        }
+    }
+
+    pub fn evaluate<T>(&self, graph: &UndirectedGraph<T>, communities: Vec<Community<T>>) -> f64
+    where
+        T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+    {
+        match self {
+            Self::Modularity { resolution } => {
+                graph.get_modularity_with_resolution(communities, *resolution)
+            }
+            Self::ConstantPotts { resolution } => {
+                graph.get_constant_potts(communities, *resolution)
+            }
+        }
+    }
+
+    /*
+     *  Penalidade da fusão de duas comunidades, na escala usada pelo agrupamento de Newman
+     *  (ganho = 2 * e_ij - penalidade, com e_ij normalizado por `total_of_edges` = 2m).
+     *  Recebe graus e tamanhos das duas comunidades.
+     */
+    pub fn get_merge_penalty(
+        &self,
+        degrees: (f64, f64),
+        sizes: (f64, f64),
+        total_of_edges: f64,
+    ) -> f64 {
+        match self {
+            Self::Modularity { resolution } => {
+                2. * resolution * degrees.0 * degrees.1 / total_of_edges.powf(2.)
+            }
+            Self::ConstantPotts { resolution } => {
+                2. * resolution * sizes.0 * sizes.1 / total_of_edges
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Dois K4 ligados pela aresta 0-4: m = 13
+    fn two_cliques() -> (
+        UndirectedGraph<usize>,
+        Vec<Community<usize>>,
+        Vec<Community<usize>>,
+    ) {
+        let mut edges: Vec<[usize; 2]> = vec![[0, 4]];
+        for start in [0, 4] {
+            for u in start..start + 4 {
+                for v in u + 1..start + 4 {
+                    edges.push([u, v]);
+                }
+            }
+        }
+        let split = vec![(0..4).collect(), (4..8).collect()];
+        let single = vec![(0..8).collect()];
+        (UndirectedGraph::from(edges), split, single)
+    }
+
+    fn assert_close(x: f64, y: f64) {
+        assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+    }
+
+    #[test]
+    fn modularity_with_resolution_on_two_cliques() {
+        let (graph, split, single) = two_cliques();
+
+        for resolution in [0.5, 1., 2.] {
+            let quality = QualityFunction::Modularity { resolution };
+            assert_close(
+                quality.evaluate(&graph, split.clone()),
+                12. / 13. - resolution / 2.,
+            );
+            assert_close(quality.evaluate(&graph, single.clone()), 1. - resolution);
+        }
+    }
+
+    #[test]
+    fn constant_potts_on_two_cliques() {
+        let (graph, split, single) = two_cliques();
+
+        for resolution in [0., 0.25, 1.] {
+            let quality = QualityFunction::ConstantPotts { resolution };
+            assert_close(
+                quality.evaluate(&graph, split.clone()),
+                12. - 12. * resolution,
+            );
+            assert_close(
+                quality.evaluate(&graph, single.clone()),
+                13. - 28. * resolution,
+            );
+        }
+    }
+
+    #[test]
+    fn merge_penalty_matches_the_change_in_quality() {
+        let (graph, split, single) = two_cliques();
+        let total_of_edges = graph.get_total_of_edges() as f64;
+        // Uma aresta entre as cliques: e_ij = 1 / 2m
+        let gain = |quality: QualityFunction| {
+            2. / total_of_edges - quality.get_merge_penalty((13., 13.), (4., 4.), total_of_edges)
+        };
+
+        let modularity = QualityFunction::default();
+        assert_close(
+            gain(modularity),
+            modularity.evaluate(&graph, single.clone())
+                - modularity.evaluate(&graph, split.clone()),
+        );
+
+        // O CPM está em arestas: a variação da qualidade é o ganho vezes m
+        let potts = QualityFunction::ConstantPotts { resolution: 0.25 };
+        assert_close(
+            gain(potts) * total_of_edges / 2.,
+            potts.evaluate(&graph, single) - potts.evaluate(&graph, split),
+        );
+    }
+}
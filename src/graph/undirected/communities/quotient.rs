@@ -0,0 +1,199 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::graph::undirected::{Community, UndirectedGraph};
+
+/*
+ *  Grafo agregado de uma partição: o vértice i é a comunidade i, `weights[(i, j)]` (com i < j)
+ *  conta as arestas entre as duas comunidades e `internal[i]` as arestas dentro da i (o peso
+ *  do laço)
+ */
+#[derive(Debug, Clone, Default)]
+pub struct QuotientGraph {
+    pub sizes: Vec<usize>,
+    pub internal: Vec<usize>,
+    pub weights: BTreeMap<(usize, usize), usize>,
+}
+
+impl QuotientGraph {
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    // Soma dos graus dos vértices da comunidade: o laço conta duas vezes
+    pub fn get_degrees(&self) -> Vec<usize> {
+        let mut degrees: Vec<usize> = self.internal.iter().map(|internal| 2 * internal).collect();
+        for ((i, j), weight) in &self.weights {
+            degrees[*i] += weight;
+            degrees[*j] += weight;
+        }
+        degrees
+    }
+
+    pub fn get_total_of_edges(&self) -> usize {
+        self.internal.iter().sum::<usize>() + self.weights.values().sum::<usize>()
+    }
+
+    // Modularidade da partição original calculada só com os pesos agregados
+    pub fn get_modularity(&self, resolution: f64) -> f64 {
+        let m = self.get_total_of_edges() as f64;
+        if m == 0. {
+            return 0.;
+        }
+
+        self.internal
+            .iter()
+            .zip(self.get_degrees())
+            .map(|(internal, degree)| {
+                *internal as f64 / m - resolution * (degree as f64 / (2. * m)).powi(2)
+            })
+            .sum()
+    }
+
+    // Topologia sem pesos e sem laços, para reaproveitar os algoritmos do grafo original
+    pub fn to_graph(&self) -> UndirectedGraph<usize> {
+        let mut graph = UndirectedGraph::new();
+        for community in 0..self.len() {
+            graph.push_vertex(community);
+        }
+        for (i, j) in self.weights.keys() {
+            graph.push_undirected_edge(*i, *j);
+        }
+        graph
+    }
+
+    // Uma linha `i j peso` por par de comunidades ligadas, com os laços como `i i peso`
+    pub fn to_edge_list(&self) -> String {
+        let mut lines = String::new();
+        for (community, internal) in self.internal.iter().enumerate() {
+            if *internal > 0 {
+                lines += &format!("{} {} {}\n", community, community, internal);
+            }
+        }
+        for ((i, j), weight) in &self.weights {
+            lines += &format!("{} {} {}\n", i, j, weight);
+        }
+        lines
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    // Arestas com alguma ponta fora de `communities` são ignoradas
+    pub fn get_quotient_graph(&self, communities: &[Community<T>]) -> QuotientGraph {
+        let mut membership: HashMap<&T, usize> = HashMap::new();
+        for (i, community) in communities.iter().enumerate() {
+            for vertex in community {
+                membership.insert(vertex, i);
+            }
+        }
+
+        let mut quotient = QuotientGraph {
+            sizes: communities
+                .iter()
+                .map(|community| community.len())
+                .collect(),
+            internal: vec![0; communities.len()],
+            weights: BTreeMap::new(),
+        };
+
+        // Cada aresta aparece nos dois sentidos: entre comunidades, conta só o sentido de menor
+        // para maior comunidade; dentro delas, conta os dois e divide por 2 no fim
+        for (vertex, neighbourhood) in &self.adjacency {
+            let Some(&i) = membership.get(vertex) else {
+                continue;
+            };
+            for neighbour in neighbourhood.iter().filter(|n| *n != vertex) {
+                let Some(&j) = membership.get(neighbour) else {
+                    continue;
+                };
+                match i.cmp(&j) {
+                    std::cmp::Ordering::Equal => quotient.internal[i] += 1,
+                    std::cmp::Ordering::Less => *quotient.weights.entry((i, j)).or_insert(0) += 1,
+                    std::cmp::Ordering::Greater => {}
+                }
+            }
+        }
+
+        quotient
+            .internal
+            .iter_mut()
+            .for_each(|internal| *internal /= 2);
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::Generators;
+
+    #[test]
+    fn quotient_weights_sum_to_the_edges() {
+        let planted = Generators::relaxed_caveman(6, 7, 0.25, 12);
+        let graph = planted.graph;
+        let quotient = graph.get_quotient_graph(&planted.ground_truth);
+
+        assert_eq!(quotient.len(), 6);
+        assert_eq!(
+            quotient.get_total_of_edges(),
+            graph.get_total_of_edges() / 2
+        );
+        assert_eq!(
+            quotient.get_degrees().iter().sum::<usize>(),
+            graph.get_total_of_edges()
+        );
+        assert!(
+            (quotient.get_modularity(1.) - graph.get_modularity(planted.ground_truth.clone()))
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            (quotient.get_modularity(0.5)
+                - graph.get_modularity_with_resolution(planted.ground_truth, 0.5))
+            .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn two_triangles_joined_by_two_edges() {
+        let graph = UndirectedGraph::from(vec![
+            [0, 1],
+            [1, 2],
+            [2, 0],
+            [3, 4],
+            [4, 5],
+            [5, 3],
+            [0, 3],
+            [1, 4],
+        ]);
+        let communities = vec![Community::from([0, 1, 2]), Community::from([3, 4, 5])];
+        let quotient = graph.get_quotient_graph(&communities);
+
+        assert_eq!(quotient.sizes, vec![3, 3]);
+        assert_eq!(quotient.internal, vec![3, 3]);
+        assert_eq!(quotient.weights, BTreeMap::from([((0, 1), 2)]));
+        assert_eq!(quotient.get_degrees(), vec![8, 8]);
+        assert_eq!(quotient.to_edge_list(), "0 0 3\n1 1 3\n0 1 2\n");
+        assert_eq!(quotient.to_graph().get_total_of_edges(), 2);
+    }
+
+    #[test]
+    fn vertices_outside_the_partition_are_ignored() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3]]);
+        let quotient = graph.get_quotient_graph(&[Community::from([0, 1])]);
+
+        assert_eq!(quotient.get_total_of_edges(), 1);
+        assert!(quotient.weights.is_empty());
+    }
+}
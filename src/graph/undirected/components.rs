@@ -0,0 +1,210 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{Community, UndirectedGraph};
+
+/*
+ *  Conjuntos disjuntos com união por tamanho e compressão de caminho. Permite juntar
+ *  componentes aos poucos, à medida que arestas são inseridas, sem refazer a busca.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind<T> {
+    index: HashMap<T, usize>,
+    elements: Vec<T>,
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl<T> UnionFind<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            elements: Vec::new(),
+            parent: Vec::new(),
+            size: Vec::new(),
+            components: 0,
+        }
+    }
+
+    // Registra `element` como um conjunto unitário, se ainda não existir
+    pub fn insert(&mut self, element: T) -> usize {
+        if let Some(position) = self.index.get(&element) {
+            return *position;
+        }
+
+        let position = self.elements.len();
+        self.index.insert(element.clone(), position);
+        self.elements.push(element);
+        self.parent.push(position);
+        self.size.push(1);
+        self.components += 1;
+        position
+    }
+
+    fn root(&mut self, mut position: usize) -> usize {
+        while self.parent[position] != position {
+            self.parent[position] = self.parent[self.parent[position]];
+            position = self.parent[position];
+        }
+        position
+    }
+
+    // Representante do conjunto de `element`
+    pub fn find(&mut self, element: &T) -> Option<usize> {
+        let position = *self.index.get(element)?;
+        Some(self.root(position))
+    }
+
+    // Junta os conjuntos de `x` e `y`, inserindo-os se necessário. Retorna falso se já estavam juntos
+    pub fn union(&mut self, x: T, y: T) -> bool {
+        let (x, y) = (self.insert(x), self.insert(y));
+        let (mut x, mut y) = (self.root(x), self.root(y));
+        if x == y {
+            return false;
+        }
+
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, x: &T, y: &T) -> bool {
+        match (self.find(x), self.find(y)) {
+            (Some(x), Some(y)) => x == y,
+            _ => false,
+        }
+    }
+
+    pub fn get_size_of(&mut self, element: &T) -> usize {
+        self.find(element).map_or(0, |root| self.size[root])
+    }
+
+    pub fn get_quantity_of_components(&self) -> usize {
+        self.components
+    }
+
+    pub fn get_components(&mut self) -> Vec<Community<T>> {
+        let mut components: HashMap<usize, Community<T>> = HashMap::new();
+        for position in 0..self.elements.len() {
+            let root = self.root(position);
+            components
+                .entry(root)
+                .or_default()
+                .insert(self.elements[position].clone());
+        }
+
+        components.into_values().collect()
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    /*
+     *  Conjuntos disjuntos com um conjunto por vértice (inclusive os isolados, sem entrada em
+     *  `adjacency`) e todas as arestas já unidas. O(n + m).
+     */
+    pub fn get_union_find(&self) -> UnionFind<T> {
+        let mut union_find = UnionFind::new();
+        for vertex in &self.vertices {
+            union_find.insert(vertex.clone());
+        }
+        for (vertex, neighbourhood) in &self.adjacency {
+            for neighbour in neighbourhood {
+                union_find.union(vertex.clone(), neighbour.clone());
+            }
+        }
+
+        union_find
+    }
+
+    pub fn get_connected_components(&self) -> Vec<Community<T>> {
+        self.get_union_find().get_components()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+
+    use super::*;
+    use crate::graph::generators::Generators;
+
+    fn breadth_first_components(graph: &UndirectedGraph<usize>) -> HashSet<Vec<usize>> {
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut components = HashSet::new();
+
+        for source in &graph.vertices {
+            if !visited.insert(*source) {
+                continue;
+            }
+            let mut component = vec![*source];
+            let mut queue = VecDeque::from([*source]);
+            while let Some(vertex) = queue.pop_front() {
+                for neighbour in graph.get_neighbourhood(&vertex).into_iter().flatten() {
+                    if visited.insert(*neighbour) {
+                        component.push(*neighbour);
+                        queue.push_back(*neighbour);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.insert(component);
+        }
+
+        components
+    }
+
+    #[test]
+    fn components_match_breadth_first_search() {
+        // Com p abaixo de 1/n sobram muitas componentes pequenas e vértices isolados
+        for seed in 0..5 {
+            let graph = Generators::erdos_renyi_gnp(300, 0.003, seed);
+            let components: HashSet<Vec<usize>> = graph
+                .get_connected_components()
+                .into_iter()
+                .map(|component| {
+                    let mut component: Vec<usize> = component.into_iter().collect();
+                    component.sort_unstable();
+                    component
+                })
+                .collect();
+
+            assert!(components.len() > 1);
+            assert_eq!(components, breadth_first_components(&graph));
+        }
+    }
+
+    #[test]
+    fn union_find_operations() {
+        let mut union_find = UnionFind::new();
+        for element in 0..6 {
+            union_find.insert(element);
+        }
+
+        assert!(union_find.union(0, 1));
+        assert!(union_find.union(2, 3));
+        assert!(union_find.union(1, 3));
+        assert!(!union_find.union(0, 2));
+        assert!(union_find.connected(&0, &3));
+        assert!(!union_find.connected(&0, &4));
+        assert_eq!(union_find.get_size_of(&2), 4);
+        assert_eq!(union_find.get_quantity_of_components(), 3);
+        assert_eq!(union_find.find(&9), None);
+        // Uma união com elemento novo o insere
+        assert!(union_find.union(5, 9));
+        assert_eq!(union_find.get_components().len(), 3);
+    }
+}
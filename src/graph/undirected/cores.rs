@@ -0,0 +1,218 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{UndirectedGraph, indexed::IndexedGraph};
+use crate::graph::edge::Edge;
+
+impl<T> IndexedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone,
+{
+    /*
+     *  Batagelj–Zaversnik: vértices ordenados por grau em baldes; ao remover o de menor grau,
+     *  cada vizinho de grau maior desce um balde. O(n + m).
+     */
+    pub(crate) fn get_core_numbers(&self) -> Vec<usize> {
+        let n = self.len();
+        // Laços não contam no grau
+        let mut degree: Vec<usize> = (0..n)
+            .map(|vertex| {
+                self.adjacency[vertex]
+                    .iter()
+                    .filter(|neighbour| **neighbour != vertex)
+                    .count()
+            })
+            .collect();
+        let max_degree = degree.iter().copied().max().unwrap_or(0);
+
+        // `bin[d]` é a posição inicial do balde de grau d em `order`
+        let mut bin = vec![0; max_degree + 1];
+        for d in &degree {
+            bin[*d] += 1;
+        }
+        let mut start = 0;
+        for size in bin.iter_mut() {
+            let count = *size;
+            *size = start;
+            start += count;
+        }
+
+        let mut position = vec![0; n];
+        let mut order = vec![0; n];
+        for vertex in 0..n {
+            position[vertex] = bin[degree[vertex]];
+            order[position[vertex]] = vertex;
+            bin[degree[vertex]] += 1;
+        }
+        for d in (1..=max_degree).rev() {
+            bin[d] = bin[d - 1];
+        }
+        bin[0] = 0;
+
+        for i in 0..n {
+            let vertex = order[i];
+            for &neighbour in &self.adjacency[vertex] {
+                if degree[neighbour] > degree[vertex] {
+                    // Troca o vizinho com o primeiro vértice do seu balde e encolhe o balde
+                    let d = degree[neighbour];
+                    let first = order[bin[d]];
+                    if first != neighbour {
+                        order.swap(position[neighbour], bin[d]);
+                        position[first] = position[neighbour];
+                        position[neighbour] = bin[d];
+                    }
+                    bin[d] += 1;
+                    degree[neighbour] -= 1;
+                }
+            }
+        }
+
+        degree
+    }
+
+    /*
+     *  Cada aresta recebe o maior k tal que ela pertence ao k-truss (todo aresta do subgrafo
+     *  está em pelo menos k - 2 triângulos dele). As arestas são removidas em ordem crescente
+     *  de suporte, atualizando o suporte das arestas dos triângulos desfeitos. Laços não
+     *  formam triângulos e ficam sem número.
+     */
+    pub(crate) fn get_truss_numbers(&self) -> HashMap<(usize, usize), usize> {
+        let mut alive: Vec<HashSet<usize>> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .map(|(u, neighbourhood)| neighbourhood.iter().copied().filter(|w| *w != u).collect())
+            .collect();
+        let key = |u: usize, v: usize| (u.min(v), u.max(v));
+
+        let mut support: HashMap<(usize, usize), usize> = HashMap::new();
+        for (u, neighbourhood) in alive.iter().enumerate() {
+            for &v in neighbourhood.iter().filter(|v| u < **v) {
+                let common = neighbourhood
+                    .iter()
+                    .filter(|w| alive[v].contains(w))
+                    .count();
+                support.insert((u, v), common);
+            }
+        }
+
+        let mut heap: BinaryHeap<Reverse<(usize, (usize, usize))>> = support
+            .iter()
+            .map(|(edge, support)| Reverse((*support, *edge)))
+            .collect();
+        let mut truss: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut level = 2;
+
+        while let Some(Reverse((edge_support, (u, v)))) = heap.pop() {
+            // Entradas antigas de arestas já removidas ou com suporte desatualizado
+            if truss.contains_key(&(u, v)) || support[&(u, v)] != edge_support {
+                continue;
+            }
+
+            level = level.max(edge_support + 2);
+            truss.insert((u, v), level);
+
+            let common: Vec<usize> = alive[u]
+                .iter()
+                .copied()
+                .filter(|w| alive[v].contains(w))
+                .collect();
+            for w in common {
+                for edge in [key(u, w), key(v, w)] {
+                    let value = support.get_mut(&edge).unwrap();
+                    *value -= 1;
+                    heap.push(Reverse((*value, edge)));
+                }
+            }
+
+            alive[u].remove(&v);
+            alive[v].remove(&u);
+        }
+
+        truss
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn get_core_numbers(&self) -> HashMap<T, usize> {
+        let indexed = self.to_indexed();
+        let cores = indexed.get_core_numbers();
+        indexed.label(cores)
+    }
+
+    // As pontas de cada aresta seguem a ordem estável de `Utils`
+    pub fn get_truss_numbers(&self) -> HashMap<Edge<T>, usize> {
+        let indexed = self.to_indexed();
+
+        indexed
+            .get_truss_numbers()
+            .into_iter()
+            .map(|((u, v), truss)| {
+                let edge = Edge {
+                    from: indexed.vertices[u].clone(),
+                    to: indexed.vertices[v].clone(),
+                };
+                (edge, truss)
+            })
+            .collect()
+    }
+
+    // Subgrafo induzido pelos vértices de núcleo pelo menos `k`
+    pub fn get_k_core(&self, k: usize) -> UndirectedGraph<T> {
+        let vertices: HashSet<T> = self
+            .get_core_numbers()
+            .into_iter()
+            .filter(|(_, core)| *core >= k)
+            .map(|(vertex, _)| vertex)
+            .collect();
+
+        self.induced_subgraph(&vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // K4 em 0..=3, a aresta pendente 3-4 e um laço em 0
+    fn graph_with_self_loop() -> UndirectedGraph<usize> {
+        UndirectedGraph::from(vec![
+            [0, 1],
+            [0, 2],
+            [0, 3],
+            [1, 2],
+            [1, 3],
+            [2, 3],
+            [3, 4],
+            [0, 0],
+        ])
+    }
+
+    #[test]
+    fn core_numbers_ignore_self_loops() {
+        let cores = graph_with_self_loop().get_core_numbers();
+
+        for vertex in 0..4 {
+            assert_eq!(cores[&vertex], 3);
+        }
+        assert_eq!(cores[&4], 1);
+    }
+
+    #[test]
+    fn truss_numbers_ignore_self_loops() {
+        let truss = graph_with_self_loop().get_truss_numbers();
+
+        assert_eq!(truss.len(), 7);
+        for (edge, value) in truss {
+            let pendant = [edge.from, edge.to].contains(&4);
+            assert_eq!(value, if pendant { 2 } else { 4 });
+        }
+    }
+}
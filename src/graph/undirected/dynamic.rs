@@ -0,0 +1,424 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{Community, UndirectedGraph, communities::partition::Partition};
+use crate::graph::edge::Edge;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphUpdate<T> {
+    InsertVertex(T),
+    RemoveVertex(T),
+    InsertEdge(T, T),
+    RemoveEdge(T, T),
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UpdateReport {
+    pub applied: usize,
+    pub affected: usize,
+    pub moved: usize,
+    pub modularity_before: f64,
+    pub modularity: f64,
+}
+
+/*
+ *  Partição mantida junto com as somas necessárias para calcular a modularidade em O(1):
+ *  Q = L_in / m - resolution * S / (4m²), com L_in o total de arestas internas e S a soma dos
+ *  quadrados dos graus das comunidades
+ */
+#[derive(Debug, Clone)]
+pub struct IncrementalPartition<T> {
+    pub membership: HashMap<T, usize>,
+    pub resolution: f64,
+    internal_edges: HashMap<usize, usize>,
+    degrees: HashMap<usize, usize>,
+    sizes: HashMap<usize, usize>,
+    total_of_edges: usize,
+    total_internal_edges: usize,
+    squared_degrees: u128,
+    next_id: usize,
+}
+
+impl<T> IncrementalPartition<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn new(graph: &UndirectedGraph<T>, communities: &[Community<T>], resolution: f64) -> Self {
+        let mut partition = Self {
+            membership: HashMap::new(),
+            resolution,
+            internal_edges: HashMap::new(),
+            degrees: HashMap::new(),
+            sizes: HashMap::new(),
+            total_of_edges: graph.get_total_of_edges() / 2,
+            total_internal_edges: 0,
+            squared_degrees: 0,
+            next_id: 0,
+        };
+
+        for community in communities {
+            for vertex in community {
+                partition
+                    .membership
+                    .insert(vertex.clone(), partition.next_id);
+            }
+            partition.next_id += 1;
+        }
+        // Vértices fora de `communities` viram comunidades unitárias
+        for vertex in &graph.vertices {
+            if !partition.membership.contains_key(vertex) {
+                partition
+                    .membership
+                    .insert(vertex.clone(), partition.next_id);
+                partition.next_id += 1;
+            }
+        }
+
+        for (vertex, community) in &partition.membership {
+            let neighbourhood = graph.get_neighbourhood(vertex).map_or(&[][..], |n| n);
+            *partition.sizes.entry(*community).or_insert(0) += 1;
+            *partition.degrees.entry(*community).or_insert(0) += neighbourhood.len();
+            let internal = neighbourhood
+                .iter()
+                .filter(|n| partition.membership.get(*n) == Some(community))
+                .count();
+            *partition.internal_edges.entry(*community).or_insert(0) += internal;
+        }
+
+        for internal in partition.internal_edges.values_mut() {
+            *internal /= 2;
+            partition.total_internal_edges += *internal;
+        }
+        partition.squared_degrees = partition
+            .degrees
+            .values()
+            .map(|degree| (*degree as u128).pow(2))
+            .sum();
+
+        partition
+    }
+
+    pub fn get_modularity(&self) -> f64 {
+        if self.total_of_edges == 0 {
+            return 0.;
+        }
+
+        let m = self.total_of_edges as f64;
+        self.total_internal_edges as f64 / m
+            - self.resolution * self.squared_degrees as f64 / (4. * m * m)
+    }
+
+    pub fn get_communities(&self) -> Vec<Community<T>> {
+        let mut communities: HashMap<usize, Community<T>> = HashMap::new();
+        for (vertex, community) in &self.membership {
+            communities
+                .entry(*community)
+                .or_default()
+                .insert(vertex.clone());
+        }
+
+        let mut ids: Vec<usize> = communities.keys().copied().collect();
+        ids.sort_unstable();
+        ids.iter()
+            .map(|id| communities.remove(id).unwrap())
+            .collect()
+    }
+
+    pub fn to_partition(&self) -> Partition<T> {
+        Partition::with_quality(self.get_communities(), self.get_modularity())
+    }
+
+    fn set_degree(&mut self, community: usize, degree: usize) {
+        let old = self.degrees.insert(community, degree).unwrap_or(0);
+        self.squared_degrees =
+            self.squared_degrees + (degree as u128).pow(2) - (old as u128).pow(2);
+    }
+
+    fn add_internal(&mut self, community: usize, delta: isize) {
+        let internal = self.internal_edges.entry(community).or_insert(0);
+        *internal = internal.checked_add_signed(delta).unwrap();
+        self.total_internal_edges = self.total_internal_edges.checked_add_signed(delta).unwrap();
+    }
+
+    fn insert_vertex(&mut self, vertex: &T) {
+        if self.membership.contains_key(vertex) {
+            return;
+        }
+
+        self.membership.insert(vertex.clone(), self.next_id);
+        self.sizes.insert(self.next_id, 1);
+        self.degrees.insert(self.next_id, 0);
+        self.internal_edges.insert(self.next_id, 0);
+        self.next_id += 1;
+    }
+
+    fn remove_vertex(&mut self, vertex: &T) {
+        if let Some(community) = self.membership.remove(vertex) {
+            let size = self.sizes.get_mut(&community).unwrap();
+            *size -= 1;
+            if *size == 0 {
+                self.sizes.remove(&community);
+                self.set_degree(community, 0);
+                self.degrees.remove(&community);
+                self.internal_edges.remove(&community);
+            }
+        }
+    }
+
+    fn change_edge(&mut self, from: &T, to: &T, inserted: bool) {
+        let (community_from, community_to) = (self.membership[from], self.membership[to]);
+        let delta: isize = if inserted { 1 } else { -1 };
+
+        self.total_of_edges = self.total_of_edges.checked_add_signed(delta).unwrap();
+        for community in [community_from, community_to] {
+            let degree = self.degrees[&community].checked_add_signed(delta).unwrap();
+            self.set_degree(community, degree);
+        }
+        if community_from == community_to {
+            self.add_internal(community_from, delta);
+        }
+    }
+
+    /*
+     *  Move `vertex` para a comunidade vizinha de maior ganho de modularidade, no estilo da fase
+     *  local do Louvain. Retorna verdadeiro se o vértice mudou de comunidade.
+     */
+    fn move_to_best_community(&mut self, graph: &UndirectedGraph<T>, vertex: &T) -> bool {
+        let neighbourhood = match graph.get_neighbourhood(vertex) {
+            Some(neighbourhood) if !neighbourhood.is_empty() => neighbourhood,
+            _ => return false,
+        };
+
+        let current = self.membership[vertex];
+        let degree = neighbourhood.len() as f64;
+        let m = self.total_of_edges as f64;
+
+        let mut links: HashMap<usize, usize> = HashMap::new();
+        for neighbour in neighbourhood {
+            *links.entry(self.membership[neighbour]).or_insert(0) += 1;
+        }
+
+        // Ganho de inserir o vértice (já retirado da sua comunidade) em `community`
+        let gain = |community: usize, links: usize| {
+            let mut community_degree = self.degrees[&community] as f64;
+            if community == current {
+                community_degree -= degree;
+            }
+            links as f64 / m - self.resolution * degree * community_degree / (2. * m * m)
+        };
+
+        let current_gain = gain(current, links.get(&current).copied().unwrap_or(0));
+        let mut candidates: Vec<(&usize, &usize)> = links.iter().collect();
+        candidates.sort_unstable();
+        let best = candidates
+            .into_iter()
+            .filter(|(community, _)| **community != current)
+            .map(|(community, links)| (*community, *links, gain(*community, *links)))
+            .max_by(|x, y| x.2.total_cmp(&y.2));
+
+        let Some((target, target_links, target_gain)) = best else {
+            return false;
+        };
+        if target_gain <= current_gain + 1e-12 {
+            return false;
+        }
+
+        let current_links = links.get(&current).copied().unwrap_or(0);
+        let degree = neighbourhood.len();
+
+        self.add_internal(current, -(current_links as isize));
+        self.set_degree(current, self.degrees[&current] - degree);
+        *self.sizes.get_mut(&current).unwrap() -= 1;
+        if self.sizes[&current] == 0 {
+            self.sizes.remove(&current);
+            self.degrees.remove(&current);
+            self.internal_edges.remove(&current);
+        }
+
+        self.add_internal(target, target_links as isize);
+        self.set_degree(target, self.degrees[&target] + degree);
+        *self.sizes.get_mut(&target).unwrap() += 1;
+        self.membership.insert(vertex.clone(), target);
+
+        true
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    /*
+     *  Aplica um lote de inserções e remoções e atualiza `partition` localmente: somente os
+     *  vértices tocados pelo lote (e, em cascata, os vizinhos dos que mudarem de comunidade)
+     *  são reavaliados. A modularidade é mantida por deltas, sem recalcular `get_modularity`.
+     */
+    pub fn apply_updates(
+        &mut self,
+        updates: &[GraphUpdate<T>],
+        partition: &mut IncrementalPartition<T>,
+    ) -> UpdateReport {
+        let mut report = UpdateReport {
+            modularity_before: partition.get_modularity(),
+            ..Default::default()
+        };
+        let mut affected: HashSet<T> = HashSet::new();
+
+        for update in updates {
+            match update {
+                GraphUpdate::InsertVertex(vertex) => {
+                    self.push_vertex(vertex.clone());
+                    partition.insert_vertex(vertex);
+                }
+                GraphUpdate::InsertEdge(from, to) => {
+                    if from == to || self.has_edge(from, to) {
+                        continue;
+                    }
+                    self.push_undirected_edge(from.clone(), to.clone());
+                    partition.insert_vertex(from);
+                    partition.insert_vertex(to);
+                    partition.change_edge(from, to, true);
+                    affected.extend([from.clone(), to.clone()]);
+                }
+                GraphUpdate::RemoveEdge(from, to) => {
+                    if !self.has_edge(from, to) {
+                        continue;
+                    }
+                    self.remove_edge(&Edge {
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
+                    partition.change_edge(from, to, false);
+                    affected.extend([from.clone(), to.clone()]);
+                }
+                GraphUpdate::RemoveVertex(vertex) => {
+                    if !self.vertices.contains(vertex) {
+                        continue;
+                    }
+                    for neighbour in self.get_neighbourhood(vertex).cloned().unwrap_or_default() {
+                        partition.change_edge(vertex, &neighbour, false);
+                        affected.insert(neighbour);
+                    }
+                    self.remove_vertex(vertex);
+                    partition.remove_vertex(vertex);
+                    affected.remove(vertex);
+                }
+            }
+            report.applied += 1;
+        }
+
+        report.affected = affected.len();
+
+        let mut queue: VecDeque<T> = affected.iter().cloned().collect();
+        let mut queued: HashSet<T> = affected;
+        let max_moves = 10 * self.vertices.len().max(1);
+
+        while let Some(vertex) = queue.pop_front() {
+            queued.remove(&vertex);
+            if report.moved >= max_moves || !partition.move_to_best_community(self, &vertex) {
+                continue;
+            }
+
+            report.moved += 1;
+            for neighbour in self.get_neighbourhood(&vertex).into_iter().flatten() {
+                if queued.insert(neighbour.clone()) {
+                    queue.push_back(neighbour.clone());
+                }
+            }
+        }
+
+        report.modularity = partition.get_modularity();
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph::generators::Generators, utils::random::Random};
+
+    fn from_scratch(
+        graph: &UndirectedGraph<usize>,
+        partition: &IncrementalPartition<usize>,
+    ) -> f64 {
+        graph.get_modularity_with_resolution(partition.get_communities(), partition.resolution)
+    }
+
+    fn random_batch(
+        graph: &UndirectedGraph<usize>,
+        random: &mut Random,
+    ) -> Vec<GraphUpdate<usize>> {
+        let n = graph.vertices.len() + 2;
+        (0..15)
+            .map(|_| {
+                let (u, v) = (random.gen_range(n), random.gen_range(n));
+                match random.gen_range(10) {
+                    0 => GraphUpdate::InsertVertex(n + u),
+                    1 => GraphUpdate::RemoveVertex(u),
+                    2..=5 => GraphUpdate::InsertEdge(u, v),
+                    _ => match graph.get_neighbourhood(&u).and_then(|n| n.first()) {
+                        Some(neighbour) => GraphUpdate::RemoveEdge(u, *neighbour),
+                        None => GraphUpdate::RemoveEdge(u, v),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn maintained_modularity_matches_a_full_recomputation() {
+        for resolution in [0.5, 1., 1.5] {
+            let planted = Generators::relaxed_caveman(5, 6, 0.2, 4);
+            let mut graph = planted.graph;
+            let mut partition =
+                IncrementalPartition::new(&graph, &planted.ground_truth, resolution);
+            let mut random = Random::new(17);
+
+            assert!((partition.get_modularity() - from_scratch(&graph, &partition)).abs() < 1e-12);
+            for _ in 0..20 {
+                let batch = random_batch(&graph, &mut random);
+                let report = graph.apply_updates(&batch, &mut partition);
+
+                assert!(
+                    (partition.get_modularity() - from_scratch(&graph, &partition)).abs() < 1e-12,
+                    "{} != {}",
+                    partition.get_modularity(),
+                    from_scratch(&graph, &partition)
+                );
+                assert!((report.modularity - partition.get_modularity()).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn local_moves_never_lower_modularity() {
+        let graph = Generators::relaxed_caveman(4, 8, 0.3, 2).graph;
+        let mut random = Random::new(5);
+        // Partição inicial aleatória, para que haja movimentos a fazer
+        let mut communities: Vec<Community<usize>> = vec![Community::new(); 6];
+        for vertex in graph.get_ordered_vertices(None) {
+            communities[random.gen_range(6)].insert(vertex);
+        }
+        let mut partition = IncrementalPartition::new(&graph, &communities, 1.);
+        let mut moved = 0;
+
+        for _ in 0..5 {
+            for vertex in graph.get_ordered_vertices(None) {
+                let before = partition.get_modularity();
+                if partition.move_to_best_community(&graph, &vertex) {
+                    moved += 1;
+                    assert!(partition.get_modularity() > before);
+                } else {
+                    assert_eq!(partition.get_modularity(), before);
+                }
+                assert!(
+                    (partition.get_modularity() - from_scratch(&graph, &partition)).abs() < 1e-12
+                );
+            }
+        }
+        assert!(moved > 0);
+    }
+}
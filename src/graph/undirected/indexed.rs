@@ -0,0 +1,80 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::UndirectedGraph;
+
+/*
+ *  Cópia do grafo com vértices numerados de 0 a n - 1 (na ordem estável de `Utils`), usada
+ *  pelos algoritmos que percorrem o grafo muitas vezes e se beneficiam de vetores no lugar de
+ *  tabelas hash
+ */
+#[derive(Debug, Clone, Default)]
+pub struct IndexedGraph<T> {
+    pub vertices: Vec<T>,
+    pub index: HashMap<T, usize>,
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl<T> IndexedGraph<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.adjacency[vertex].len()
+    }
+
+    // Espelha `UndirectedGraph::remove_edge`, para manter a cópia em dia com o grafo original
+    pub fn remove_edge(&mut self, u: usize, v: usize) {
+        for (from, to) in [(u, v), (v, u)] {
+            if let Some(position) = self.adjacency[from].iter().position(|w| *w == to) {
+                self.adjacency[from].swap_remove(position);
+            }
+        }
+    }
+
+    // Converte um vetor indexado por vértice de volta para os rótulos originais
+    pub fn label<V>(&self, values: Vec<V>) -> HashMap<T, V> {
+        self.vertices.iter().cloned().zip(values).collect()
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn to_indexed(&self) -> IndexedGraph<T> {
+        let vertices = self.get_ordered_vertices(None);
+        let index: HashMap<T, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (vertex.clone(), i))
+            .collect();
+        let adjacency = vertices
+            .iter()
+            .map(|vertex| {
+                self.get_neighbourhood(vertex)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|neighbour| index.get(neighbour).copied())
+                    .collect()
+            })
+            .collect();
+
+        IndexedGraph {
+            vertices,
+            index,
+            adjacency,
+        }
+    }
+}
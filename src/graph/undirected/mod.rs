@@ -1,6 +1,17 @@
+pub mod approximate_betweenness;
 pub mod basic_operations;
+pub mod biconnectivity;
+pub mod builder;
+pub mod centrality;
 pub mod communities;
+pub mod components;
+pub mod cores;
+pub mod dynamic;
+pub mod indexed;
 pub mod parse;
+pub mod shortest_paths;
+pub mod subgraph;
+pub mod triangles;
 
 use std::collections::{HashMap, HashSet};
 
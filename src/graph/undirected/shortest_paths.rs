@@ -0,0 +1,377 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use rayon::prelude::*;
+
+use super::UndirectedGraph;
+use crate::utils::OrderedF64;
+
+/*
+ *  Menores caminhos a partir de `source`: a distância de cada vértice alcançável e todos os
+ *  seus predecessores em algum menor caminho. `D` é `usize` na BFS e `f64` no Dijkstra.
+ */
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T, D> {
+    pub source: T,
+    pub distances: HashMap<T, D>,
+    pub predecessors: HashMap<T, Vec<T>>,
+}
+
+impl<T, D> ShortestPaths<T, D>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn get_distance(&self, target: &T) -> Option<&D> {
+        self.distances.get(target)
+    }
+
+    // Um menor caminho de `source` até `target`, seguindo sempre o primeiro predecessor
+    pub fn get_path_to(&self, target: &T) -> Option<Vec<T>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(predecessor) = self.predecessors.get(current).and_then(|p| p.first()) {
+            path.push(predecessor.clone());
+            current = predecessor;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Todos os menores caminhos até `target`; a quantidade pode crescer exponencialmente
+    pub fn get_all_paths_to(&self, target: &T) -> Vec<Vec<T>> {
+        if !self.distances.contains_key(target) {
+            return vec![];
+        }
+
+        let mut paths = Vec::new();
+        let mut stack: Vec<Vec<T>> = vec![vec![target.clone()]];
+        while let Some(partial) = stack.pop() {
+            let last = partial.last().unwrap();
+            match self.predecessors.get(last) {
+                Some(predecessors) if !predecessors.is_empty() => {
+                    for predecessor in predecessors {
+                        let mut extended = partial.clone();
+                        extended.push(predecessor.clone());
+                        stack.push(extended);
+                    }
+                }
+                _ => {
+                    let mut path = partial;
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+// Distâncias entre todos os pares; `distances[i][j]` é None quando j não é alcançável de i
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<T, D> {
+    pub vertices: Vec<T>,
+    pub index: HashMap<T, usize>,
+    pub distances: Vec<Vec<Option<D>>>,
+}
+
+impl<T, D> DistanceMatrix<T, D>
+where
+    T: Eq + Hash,
+    D: Copy,
+{
+    pub fn get(&self, from: &T, to: &T) -> Option<D> {
+        self.distances[*self.index.get(from)?][*self.index.get(to)?]
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn get_shortest_paths_from(&self, source: &T) -> ShortestPaths<T, usize> {
+        let mut paths = ShortestPaths {
+            source: source.clone(),
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        };
+        if !self.vertices.contains(source) {
+            return paths;
+        }
+
+        paths.distances.insert(source.clone(), 0);
+        let mut queue = VecDeque::from([source.clone()]);
+        while let Some(vertex) = queue.pop_front() {
+            let distance = paths.distances[&vertex];
+            for neighbour in self.get_neighbourhood(&vertex).into_iter().flatten() {
+                match paths.distances.get(neighbour) {
+                    None => {
+                        paths.distances.insert(neighbour.clone(), distance + 1);
+                        paths
+                            .predecessors
+                            .insert(neighbour.clone(), vec![vertex.clone()]);
+                        queue.push_back(neighbour.clone());
+                    }
+                    Some(d) if *d == distance + 1 => {
+                        paths
+                            .predecessors
+                            .get_mut(neighbour)
+                            .unwrap()
+                            .push(vertex.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        paths
+    }
+
+    // BFS que para assim que `to` é alcançado
+    pub fn get_shortest_path(&self, from: &T, to: &T) -> Option<Vec<T>> {
+        if !self.vertices.contains(from) || !self.vertices.contains(to) {
+            return None;
+        }
+
+        let mut predecessor: HashMap<T, T> = HashMap::new();
+        let mut queue = VecDeque::from([from.clone()]);
+        let mut found = from == to;
+        while let Some(vertex) = queue.pop_front() {
+            if found {
+                break;
+            }
+            for neighbour in self.get_neighbourhood(&vertex).into_iter().flatten() {
+                if neighbour == from || predecessor.contains_key(neighbour) {
+                    continue;
+                }
+                predecessor.insert(neighbour.clone(), vertex.clone());
+                if neighbour == to {
+                    found = true;
+                    break;
+                }
+                queue.push_back(neighbour.clone());
+            }
+        }
+
+        if !found {
+            return None;
+        }
+        let mut path = vec![to.clone()];
+        while let Some(previous) = predecessor.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn get_all_shortest_paths(&self, from: &T, to: &T) -> Vec<Vec<T>> {
+        self.get_shortest_paths_from(from).get_all_paths_to(to)
+    }
+
+    /*
+     *  Dijkstra com o peso de cada aresta dado por `weight(u, v)`, que deve ser não negativo.
+     *  Caminhos empatados (a menos de erro de arredondamento) são todos registrados.
+     */
+    pub fn get_dijkstra(
+        &self,
+        source: &T,
+        weight: impl Fn(&T, &T) -> f64,
+    ) -> ShortestPaths<T, f64> {
+        let mut paths = ShortestPaths {
+            source: source.clone(),
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        };
+        if !self.vertices.contains(source) {
+            return paths;
+        }
+
+        // O índice desempata a fila sem exigir `Ord` de `T`
+        let mut labels: Vec<T> = vec![source.clone()];
+        let mut index: HashMap<T, usize> = HashMap::from([(source.clone(), 0)]);
+        let mut settled: Vec<bool> = vec![false];
+        let mut heap = BinaryHeap::from([Reverse((OrderedF64(0.), 0))]);
+        paths.distances.insert(source.clone(), 0.);
+
+        while let Some(Reverse((OrderedF64(distance), position))) = heap.pop() {
+            if settled[position] {
+                continue;
+            }
+            settled[position] = true;
+            let vertex = labels[position].clone();
+
+            for neighbour in self.get_neighbourhood(&vertex).into_iter().flatten() {
+                let w = weight(&vertex, neighbour);
+                if w < 0. || w.is_nan() {
+                    panic!("ERROR: DIJKSTRA NEEDS NON-NEGATIVE EDGE WEIGHTS");
+                }
+                let candidate = distance + w;
+
+                let neighbour_position = *index.entry(neighbour.clone()).or_insert_with(|| {
+                    labels.push(neighbour.clone());
+                    settled.push(false);
+                    labels.len() - 1
+                });
+                if settled[neighbour_position] {
+                    continue;
+                }
+
+                match paths.distances.get(neighbour).copied() {
+                    Some(current) if (candidate - current).abs() <= 1e-12 * current.max(1.) => {
+                        paths
+                            .predecessors
+                            .get_mut(neighbour)
+                            .unwrap()
+                            .push(vertex.clone());
+                    }
+                    Some(current) if candidate >= current => {}
+                    _ => {
+                        paths.distances.insert(neighbour.clone(), candidate);
+                        paths
+                            .predecessors
+                            .insert(neighbour.clone(), vec![vertex.clone()]);
+                        heap.push(Reverse((OrderedF64(candidate), neighbour_position)));
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    // Uma BFS por vértice, em paralelo. A matriz tem n² entradas: só para grafos pequenos.
+    pub fn get_all_pairs_distances(&self) -> DistanceMatrix<T, usize> {
+        let indexed = self.to_indexed();
+
+        let distances = (0..indexed.len())
+            .into_par_iter()
+            .map(|source| {
+                indexed
+                    .shortest_paths_dag(source)
+                    .distance
+                    .into_iter()
+                    .map(|distance| (distance != usize::MAX).then_some(distance))
+                    .collect()
+            })
+            .collect();
+
+        DistanceMatrix {
+            vertices: indexed.vertices,
+            index: indexed.index,
+            distances,
+        }
+    }
+
+    pub fn get_all_pairs_weighted_distances(
+        &self,
+        weight: impl Fn(&T, &T) -> f64 + Sync,
+    ) -> DistanceMatrix<T, f64> {
+        let vertices = self.get_ordered_vertices(None);
+        let index: HashMap<T, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (vertex.clone(), i))
+            .collect();
+
+        let distances = vertices
+            .par_iter()
+            .map(|source| {
+                let paths = self.get_dijkstra(source, &weight);
+                vertices
+                    .iter()
+                    .map(|target| paths.distances.get(target).copied())
+                    .collect()
+            })
+            .collect();
+
+        DistanceMatrix {
+            vertices,
+            index,
+            distances,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::Generators;
+
+    fn sorted<T: Ord + Clone>(values: &[T]) -> Vec<T> {
+        let mut values = values.to_vec();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn dijkstra_with_unit_weights_agrees_with_breadth_first_search() {
+        // Esparso o bastante para ter vértices inalcançáveis
+        let graph = Generators::erdos_renyi_gnp(150, 0.012, 6);
+
+        for source in [0, 17, 99] {
+            let bfs = graph.get_shortest_paths_from(&source);
+            let dijkstra = graph.get_dijkstra(&source, |_, _| 1.);
+
+            assert_eq!(bfs.distances.len(), dijkstra.distances.len());
+            for (vertex, distance) in &bfs.distances {
+                assert_eq!(dijkstra.distances[vertex], *distance as f64);
+                assert_eq!(
+                    sorted(&bfs.predecessors.get(vertex).cloned().unwrap_or_default()),
+                    sorted(
+                        &dijkstra
+                            .predecessors
+                            .get(vertex)
+                            .cloned()
+                            .unwrap_or_default()
+                    ),
+                );
+            }
+        }
+
+        let unweighted = graph.get_all_pairs_distances();
+        let weighted = graph.get_all_pairs_weighted_distances(|_, _| 1.);
+        for from in [3, 40] {
+            for to in &graph.vertices {
+                assert_eq!(
+                    unweighted.get(&from, to).map(|distance| distance as f64),
+                    weighted.get(&from, to)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_avoids_heavy_edges() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [0, 2]]);
+        let weight = |u: &usize, v: &usize| if u + v == 2 { 5. } else { 1. };
+        let paths = graph.get_dijkstra(&0, weight);
+
+        assert_eq!(paths.get_distance(&2), Some(&2.));
+        assert_eq!(paths.get_path_to(&2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn all_shortest_paths_of_a_square() {
+        let graph = UndirectedGraph::from(vec![[0, 1], [1, 2], [2, 3], [3, 0]]);
+
+        assert_eq!(
+            sorted(&graph.get_all_shortest_paths(&0, &2)),
+            vec![vec![0, 1, 2], vec![0, 3, 2]]
+        );
+        assert_eq!(
+            graph.get_shortest_path(&0, &2).map(|path| path.len()),
+            Some(3)
+        );
+        assert_eq!(graph.get_shortest_path(&0, &0), Some(vec![0]));
+        assert_eq!(graph.get_shortest_path(&0, &9), None);
+        assert!(graph.get_all_shortest_paths(&0, &9).is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use super::{Community, UndirectedGraph, communities::partition::Partition};
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    // Subgrafo com os vértices de `vertices` presentes no grafo e as arestas entre eles
+    pub fn induced_subgraph(&self, vertices: &HashSet<T>) -> UndirectedGraph<T> {
+        let mut subgraph = UndirectedGraph::new();
+
+        for vertex in vertices.iter().filter(|v| self.vertices.contains(*v)) {
+            subgraph.push_vertex(vertex.clone());
+            if let Some(neighbourhood) = self.get_neighbourhood(vertex) {
+                let inside: Vec<T> = neighbourhood
+                    .iter()
+                    .filter(|neighbour| vertices.contains(*neighbour))
+                    .cloned()
+                    .collect();
+                subgraph.adjacency.insert(vertex.clone(), inside);
+            }
+        }
+
+        subgraph
+    }
+
+    // Vértices a no máximo `radius` saltos de `center`, com as arestas entre eles
+    pub fn get_ego_network(&self, center: &T, radius: usize) -> UndirectedGraph<T> {
+        if !self.vertices.contains(center) {
+            return UndirectedGraph::new();
+        }
+
+        let mut reached: HashSet<T> = HashSet::from([center.clone()]);
+        let mut queue = VecDeque::from([(center.clone(), 0)]);
+        while let Some((vertex, distance)) = queue.pop_front() {
+            if distance == radius {
+                continue;
+            }
+            for neighbour in self.get_neighbourhood(&vertex).into_iter().flatten() {
+                if reached.insert(neighbour.clone()) {
+                    queue.push_back((neighbour.clone(), distance + 1));
+                }
+            }
+        }
+
+        self.induced_subgraph(&reached)
+    }
+
+    // Subgrafo de uma comunidade; vértices sem arestas internas continuam no subgrafo
+    pub fn get_community_subgraph(&self, community: &Community<T>) -> UndirectedGraph<T> {
+        self.induced_subgraph(community)
+    }
+
+    // Um subgrafo por comunidade, na ordem de `partition.communities`
+    pub fn get_partition_subgraphs(&self, partition: &Partition<T>) -> Vec<UndirectedGraph<T>> {
+        partition
+            .communities
+            .iter()
+            .map(|community| self.get_community_subgraph(community))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Estrela de centro 0 com as folhas 1, 2 e 3; a folha 3 continua no caminho 3-4-5
+    fn spider() -> UndirectedGraph<usize> {
+        UndirectedGraph::from(vec![[0, 1], [0, 2], [0, 3], [1, 2], [3, 4], [4, 5]])
+    }
+
+    #[test]
+    fn ego_network_of_radius_zero_is_the_center() {
+        let ego = spider().get_ego_network(&0, 0);
+
+        assert_eq!(ego.vertices, HashSet::from([0]));
+        assert_eq!(ego.get_total_of_edges(), 0);
+    }
+
+    #[test]
+    fn ego_network_of_radius_one_keeps_edges_among_neighbours() {
+        let ego = spider().get_ego_network(&0, 1);
+
+        assert_eq!(ego.vertices, HashSet::from([0, 1, 2, 3]));
+        // As três arestas do centro mais a aresta 1-2 entre vizinhos
+        assert_eq!(ego.get_total_of_edges() / 2, 4);
+        assert!(ego.has_edge(&1, &2));
+        assert!(!ego.vertices.contains(&4));
+    }
+
+    #[test]
+    fn ego_network_grows_with_the_radius() {
+        let graph = spider();
+
+        assert_eq!(graph.get_ego_network(&0, 2).vertices.len(), 5);
+        assert_eq!(graph.get_ego_network(&0, 10).vertices, graph.vertices);
+        assert!(graph.get_ego_network(&9, 1).vertices.is_empty());
+    }
+
+    #[test]
+    fn community_subgraphs_drop_outside_edges() {
+        let graph = spider();
+        let partition = Partition::new(
+            &graph,
+            vec![HashSet::from([0, 1, 2]), HashSet::from([3, 5])],
+        );
+        let subgraphs = graph.get_partition_subgraphs(&partition);
+
+        assert_eq!(subgraphs[0].get_total_of_edges() / 2, 3);
+        // 3 e 5 não são vizinhos, mas continuam no subgrafo
+        assert_eq!(subgraphs[1].vertices, HashSet::from([3, 5]));
+        assert_eq!(subgraphs[1].get_total_of_edges(), 0);
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rayon::prelude::*;
+
+use super::{UndirectedGraph, indexed::IndexedGraph};
+
+impl<T> IndexedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone,
+{
+    /*
+     *  Algoritmo "compact forward": cada aresta é orientada do vértice de menor para o de maior
+     *  posição na ordem (grau, índice), e cada triângulo é achado uma única vez pela interseção
+     *  das listas orientadas (ordenadas) das pontas de cada aresta. Retorna a quantidade de
+     *  triângulos de cada vértice.
+     */
+    pub(crate) fn count_triangles(&self) -> Vec<usize> {
+        let n = self.len();
+        let rank = |vertex: usize| (self.degree(vertex), vertex);
+
+        let forward: Vec<Vec<usize>> = (0..n)
+            .into_par_iter()
+            .map(|vertex| {
+                let mut higher: Vec<usize> = self.adjacency[vertex]
+                    .iter()
+                    .copied()
+                    .filter(|neighbour| rank(*neighbour) > rank(vertex))
+                    .collect();
+                higher.sort_unstable();
+                higher.dedup();
+                higher
+            })
+            .collect();
+
+        let triangles: Vec<AtomicUsize> = (0..n).map(|_| AtomicUsize::new(0)).collect();
+        (0..n).into_par_iter().for_each(|u| {
+            for &v in &forward[u] {
+                let (mut i, mut j) = (0, 0);
+                let (x, y) = (&forward[u], &forward[v]);
+                while i < x.len() && j < y.len() {
+                    match x[i].cmp(&y[j]) {
+                        std::cmp::Ordering::Less => i += 1,
+                        std::cmp::Ordering::Greater => j += 1,
+                        std::cmp::Ordering::Equal => {
+                            for vertex in [u, v, x[i]] {
+                                triangles[vertex].fetch_add(1, Ordering::Relaxed);
+                            }
+                            i += 1;
+                            j += 1;
+                        }
+                    }
+                }
+            }
+        });
+
+        triangles
+            .into_iter()
+            .map(|count| count.into_inner())
+            .collect()
+    }
+
+    // Transitividade: 3 × triângulos / triplas conexas
+    pub(crate) fn get_transitivity(&self, triangles: &[usize]) -> f64 {
+        let triangles: usize = triangles.iter().sum();
+        let triples: usize = (0..self.len())
+            .map(|vertex| self.degree(vertex))
+            .map(|degree| degree * degree.saturating_sub(1) / 2)
+            .sum();
+
+        if triples == 0 {
+            return 0.;
+        }
+        triangles as f64 / triples as f64
+    }
+
+    // Média dos coeficientes locais sobre todos os vértices
+    pub(crate) fn get_average_clustering(&self, triangles: &[usize]) -> f64 {
+        if self.is_empty() {
+            return 0.;
+        }
+
+        self.get_clustering_coefficients(triangles)
+            .iter()
+            .sum::<f64>()
+            / self.len() as f64
+    }
+
+    // Coeficiente local de cada vértice; vértices de grau menor que 2 ficam com 0
+    pub(crate) fn get_clustering_coefficients(&self, triangles: &[usize]) -> Vec<f64> {
+        (0..self.len())
+            .map(|vertex| {
+                let degree = self.degree(vertex);
+                if degree < 2 {
+                    return 0.;
+                }
+                2. * triangles[vertex] as f64 / (degree * (degree - 1)) as f64
+            })
+            .collect()
+    }
+}
+
+impl<T> UndirectedGraph<T>
+where
+    T: Send + Sync + Eq + Hash + Clone + Debug + Display + Default,
+{
+    pub fn get_triangles(&self) -> HashMap<T, usize> {
+        let indexed = self.to_indexed();
+        let triangles = indexed.count_triangles();
+        indexed.label(triangles)
+    }
+
+    pub fn get_total_of_triangles(&self) -> usize {
+        self.to_indexed().count_triangles().iter().sum::<usize>() / 3
+    }
+
+    pub fn get_transitivity(&self) -> f64 {
+        let indexed = self.to_indexed();
+        indexed.get_transitivity(&indexed.count_triangles())
+    }
+
+    pub fn get_average_clustering(&self) -> f64 {
+        let indexed = self.to_indexed();
+        indexed.get_average_clustering(&indexed.count_triangles())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::Generators;
+
+    // K4 em 0..=3, o triângulo 3-4-5 e a aresta pendente 5-6
+    fn two_cliques() -> UndirectedGraph<usize> {
+        UndirectedGraph::from(vec![
+            [0, 1],
+            [0, 2],
+            [0, 3],
+            [1, 2],
+            [1, 3],
+            [2, 3],
+            [3, 4],
+            [4, 5],
+            [5, 3],
+            [5, 6],
+        ])
+    }
+
+    #[test]
+    fn counts_triangles_per_vertex() {
+        let graph = two_cliques();
+        let triangles = graph.get_triangles();
+
+        let expected = [3, 3, 3, 4, 1, 1, 0];
+        for (vertex, count) in expected.iter().enumerate() {
+            assert_eq!(triangles[&vertex], *count, "vertex {}", vertex);
+        }
+        assert_eq!(graph.get_total_of_triangles(), 5);
+        // 15 = 3 × 5 triângulos; triplas: 3 × 3 + 10 + 1 + 3 + 0
+        assert!((graph.get_transitivity() - 15. / 23.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn matches_neighbourhood_count_on_random_graph() {
+        let graph = Generators::erdos_renyi_gnp(120, 0.15, 42);
+        let triangles = graph.get_triangles();
+
+        for vertex in &graph.vertices {
+            let neighbourhood = graph.get_neighbourhood(vertex).cloned().unwrap_or_default();
+            assert_eq!(
+                triangles[vertex],
+                graph.get_number_of_triangles(&neighbourhood),
+                "vertex {}",
+                vertex
+            );
+        }
+    }
+
+    #[test]
+    fn mirrored_removals_match_a_fresh_copy() {
+        let mut graph = Generators::erdos_renyi_gnp(60, 0.2, 8);
+        let mut indexed = graph.to_indexed();
+
+        for edge in graph.get_edges().into_iter().step_by(3) {
+            graph.remove_edge(&edge);
+            indexed.remove_edge(indexed.index[&edge.from], indexed.index[&edge.to]);
+        }
+
+        let fresh = graph.to_indexed();
+        assert_eq!(indexed.count_triangles(), fresh.count_triangles());
+        assert_eq!(
+            graph.get_clustering_coefficients(&indexed),
+            graph.get_clustering_coefficients(&fresh)
+        );
+        assert_eq!(
+            indexed.get_average_clustering(&indexed.count_triangles()),
+            graph.get_average_clustering()
+        );
+    }
+}
@@ -1,6 +1,12 @@
-use std::{fmt::Display, fs::File, io::Write};
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
 
 use super::undirected::Community;
+use crate::utils::random::Random;
 
 pub struct Utils;
 
@@ -9,11 +15,18 @@ impl Utils {
         communities: Vec<Community<T>>,
         file_name: impl Into<String>,
     ) {
-        let mut file = File::create(String::from("./out/") + &file_name.into() + ".txt")
-            .expect("ERROR: FAILED TO PERSIST COMMUNITIES");
+        let path = String::from("./out/") + &file_name.into() + ".txt";
+        if let Some(directory) = Path::new(&path).parent() {
+            fs::create_dir_all(directory).expect("ERROR: FAILED TO CREATE OUTPUT DIRECTORY");
+        }
+
+        let mut file = File::create(path).expect("ERROR: FAILED TO PERSIST COMMUNITIES");
 
         for (i, community) in communities.iter().enumerate() {
-            for (j, vertex) in community.iter().enumerate() {
+            for (j, vertex) in Self::stable_order(community.iter().collect(), None)
+                .into_iter()
+                .enumerate()
+            {
                 if j == 0 {
                     write!(file, "{}", vertex)
                         .expect("ERROR: FAILED TO WRITE ON COMMUNITY PERSISTENCE");
@@ -28,4 +41,24 @@ impl Utils {
             }
         }
     }
+
+    // Chave de ordenação estável: rótulos numéricos ficam em ordem numérica
+    pub fn stable_key<T: Display>(vertex: &T) -> (usize, String) {
+        let label = vertex.to_string();
+        (label.len(), label)
+    }
+
+    /*
+     *  Ordena os vértices pela chave estável e, se houver um gerador, embaralha a ordem de
+     *  forma reprodutível. Usado pelo modo determinístico dos algoritmos.
+     */
+    pub fn stable_order<T: Display>(mut vertices: Vec<T>, random: Option<&mut Random>) -> Vec<T> {
+        vertices.sort_by_cached_key(Self::stable_key);
+
+        if let Some(random) = random {
+            random.shuffle(&mut vertices);
+        }
+
+        vertices
+    }
 }
@@ -1,22 +1,23 @@
 pub mod analyses;
+pub mod cli;
 pub mod file;
 pub mod graph;
 pub mod utils;
 
-use std::time::Instant;
+use std::process;
 
-use file::File;
-use graph::undirected::UndirectedGraph;
+use cli::{Cli, USAGE};
+
+// Feature `track-memory`, ligada por padrão; `--no-default-features` dispensa a contabilidade
+#[cfg(feature = "track-memory")]
+#[global_allocator]
+static ALLOCATOR: utils::memory::TrackingAllocator = utils::memory::TrackingAllocator;
 
 fn main() {
-    let graph = UndirectedGraph::<String>::from(File::read("caveman_graph_2.txt"));
-    println!("{:?}", graph);
-    let hierarchical_time = Instant::now();
-    //graph.hierarchical_growth();
-    let hierarchical_elapsed = hierarchical_time.elapsed();
-    let betweenness_time = Instant::now();
-    // graph.betweenness();
-    graph.newmans_modularity_clustering();
-    println!("Betweenness Time: {:?}", betweenness_time.elapsed());
-    println!("Hierarchical growth time: {:?}", hierarchical_elapsed);
+    let result = Cli::parse(std::env::args().skip(1)).and_then(|cli| cli.run());
+
+    if let Err(error) = result {
+        eprintln!("ERROR: {}\n\n{}", error, USAGE);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,65 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/*
+ *  Alocador global que contabiliza os bytes alocados, para estimar o pico de memória. É
+ *  instalado pela feature `track-memory`, ligada por padrão; sem ela os contadores ficam em zero.
+ */
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    // Repassa ao `System` para manter o crescimento no lugar; só a diferença é contabilizada
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let reallocated = unsafe { System.realloc(pointer, layout, new_size) };
+        if !reallocated.is_null() {
+            if new_size >= layout.size() {
+                let grown = new_size - layout.size();
+                let current = CURRENT.fetch_add(grown, Ordering::Relaxed) + grown;
+                PEAK.fetch_max(current, Ordering::Relaxed);
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        reallocated
+    }
+}
+
+impl TrackingAllocator {
+    pub fn is_installed() -> bool {
+        cfg!(feature = "track-memory")
+    }
+
+    pub fn current() -> usize {
+        CURRENT.load(Ordering::Relaxed)
+    }
+
+    pub fn peak() -> usize {
+        PEAK.load(Ordering::Relaxed)
+    }
+
+    // Reinicia o pico no uso atual e devolve esse valor como base da próxima medição
+    pub fn reset_peak() -> usize {
+        let current = Self::current();
+        PEAK.store(current, Ordering::Relaxed);
+        current
+    }
+}
@@ -1,10 +1,20 @@
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub mod json;
+pub mod memory;
+pub mod random;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct OrderedF64(pub f64);
 
 impl Eq for OrderedF64 {}
 
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for OrderedF64 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        self.0.partial_cmp(&other.0).unwrap()
     }
 }
@@ -0,0 +1,75 @@
+// Gerador xoshiro256** inicializado por splitmix64, para execuções reprodutíveis a partir de uma
+// semente
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: [u64; 4],
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut state = [0; 4];
+
+        for value in state.iter_mut() {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            *value = z ^ (z >> 31);
+        }
+
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    // Uniforme em [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Uniforme em [0, upper)
+    pub fn gen_range(&mut self, upper: usize) -> usize {
+        assert!(upper > 0, "ERROR: EMPTY RANGE");
+        let upper = upper as u64;
+        let zone = u64::MAX - (u64::MAX % upper);
+
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % upper) as usize;
+            }
+        }
+    }
+
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.gen_range(i + 1);
+            values.swap(i, j);
+        }
+    }
+
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> Option<&'a T> {
+        if values.is_empty() {
+            return None;
+        }
+
+        Some(&values[self.gen_range(values.len())])
+    }
+}